@@ -0,0 +1,30 @@
+use std::{env, fs, path::Path, process::Command};
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-env-changed=SIMPLE_SAFE_INIT_MAX_STACK_INIT_SIZE");
+    println!("cargo:rustc-check-cfg=cfg(nightly)");
+    let max_stack_init_size = match env::var("SIMPLE_SAFE_INIT_MAX_STACK_INIT_SIZE") {
+        Ok(size) => size.trim().parse::<usize>().unwrap_or_else(|_| {
+            panic!("SIMPLE_SAFE_INIT_MAX_STACK_INIT_SIZE needs to be a size in bytes, got `{size}`")
+        }),
+        Err(_) => 4096,
+    };
+    let out_dir = env::var_os("OUT_DIR").expect("OUT_DIR is set by cargo");
+    fs::write(
+        Path::new(&out_dir).join("max_stack_init_size"),
+        max_stack_init_size.to_string(),
+    )
+    .expect("cannot write to OUT_DIR");
+    // the fallible allocation APIs and the never type are only available on a nightly compiler,
+    // so we only use them when we are compiled by one.
+    let rustc = env::var_os("RUSTC").unwrap_or_else(|| "rustc".into());
+    let version = Command::new(rustc)
+        .arg("--version")
+        .output()
+        .map(|output| String::from_utf8_lossy(&output.stdout).into_owned())
+        .unwrap_or_default();
+    if version.contains("nightly") || version.contains("-dev") {
+        println!("cargo:rustc-cfg=nightly");
+    }
+}
@@ -0,0 +1,367 @@
+//! Derive macros for [simple-safe-init](https://docs.rs/simple-safe-init).
+//!
+//! Do not depend on this crate directly, enable the `derive` feature of `simple-safe-init` instead.
+
+use proc_macro::TokenStream;
+use proc_macro2::{Punct, Spacing, Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use syn::{
+    parse_macro_input, Data, DeriveInput, Error, Fields, GenericParam, Generics, Ident, Index,
+    Visibility,
+};
+
+/// Derives an init-function `init` for a struct.
+///
+/// The generated function takes an [`InitMe`] to the struct and a parameter for every field, the
+/// fields are then initialized with the given values. Fields marked with `#[init(default)]` are
+/// not taken as a parameter and initialized with [`Default::default()`] instead, fields marked with
+/// `#[init(with = "expr")]` are initialized with `expr`. Fields of type
+/// `PhantomData` or `PhantomPinned` only have a single value, so they are always initialized this
+/// way. They need to be named directly or by their path in `core::marker` or `std::marker`.
+///
+/// ```rust
+/// use core::mem::MaybeUninit;
+/// use simple_safe_init::*;
+///
+/// #[derive(Debug, Init)]
+/// struct Foo {
+///     a: u32,
+///     #[init(default)]
+///     b: Vec<u8>,
+/// }
+///
+/// let foo = init!(Foo::init(Box::new(MaybeUninit::uninit()), 42));
+/// assert_eq!(foo.a, 42);
+/// assert!(foo.b.is_empty());
+/// ```
+///
+/// [`InitMe`]: https://docs.rs/simple-safe-init/latest/simple_safe_init/struct.InitMe.html
+#[proc_macro_derive(Init, attributes(init))]
+pub fn derive_init(input: TokenStream) -> TokenStream {
+    expand(parse_macro_input!(input), false)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Derives an init-function `pin_init` for a struct.
+///
+/// Works the same as `#[derive(Init)]`, except that the generated function takes a [`PinInitMe`].
+///
+/// ```rust
+/// use core::{marker::PhantomPinned, mem::MaybeUninit};
+/// use simple_safe_init::*;
+///
+/// #[derive(PinInit)]
+/// struct Foo {
+///     msg: String,
+///     // no parameter is generated for this field
+///     _p: PhantomPinned,
+/// }
+///
+/// let foo = init!(Foo::pin_init(Box::pin(MaybeUninit::uninit()), "Hello".to_owned()));
+/// assert_eq!(foo.msg, "Hello");
+/// ```
+///
+/// [`PinInitMe`]: https://docs.rs/simple-safe-init/latest/simple_safe_init/struct.PinInitMe.html
+#[proc_macro_derive(PinInit, attributes(init))]
+pub fn derive_pin_init(input: TokenStream) -> TokenStream {
+    expand(parse_macro_input!(input), true)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Wraps a struct in `pin_data!` and adds an init-function for it.
+///
+/// Fields marked with `#[pin]` are structurally pinned. If there is at least one such field, the
+/// struct is placed inside of `pin_data!` and a `pin_init` function taking a [`PinInitMe`] is
+/// generated, otherwise the struct is left as-is and an `init` function taking an [`InitMe`] is
+/// generated.
+///
+/// The function takes a parameter for every field, except for fields marked with
+/// `#[init(default)]` (initialized with [`Default::default()`]) or `#[init(with = "expr")]`
+/// (initialized with `expr`), see `#[derive(Init)]`.
+///
+/// ```rust
+/// use core::{marker::PhantomPinned, mem::MaybeUninit};
+/// use simple_safe_init::*;
+///
+/// #[init_struct]
+/// #[derive(Debug)]
+/// struct Foo<T: Clone> {
+///     #[pin]
+///     value: T,
+///     #[init(with = "Vec::with_capacity(16)")]
+///     buf: Vec<u8>,
+///     _p: PhantomPinned,
+/// }
+///
+/// let foo = init!(Foo::pin_init(Box::pin(MaybeUninit::uninit()), 42));
+/// assert_eq!(foo.value, 42);
+/// assert!(foo.buf.capacity() >= 16);
+/// ```
+///
+/// Without pinned fields, only the `init` function is added:
+///
+/// ```rust
+/// use core::mem::MaybeUninit;
+/// use simple_safe_init::*;
+///
+/// #[init_struct]
+/// struct Point(u32, #[init(default)] u32);
+///
+/// let point = init!(Point::init(Box::new(MaybeUninit::uninit()), 4));
+/// assert_eq!((point.0, point.1), (4, 0));
+/// ```
+///
+/// Type parameter defaults are not supported, because `pin_data!` does not support them.
+///
+/// [`InitMe`]: https://docs.rs/simple-safe-init/latest/simple_safe_init/struct.InitMe.html
+/// [`PinInitMe`]: https://docs.rs/simple-safe-init/latest/simple_safe_init/struct.PinInitMe.html
+#[proc_macro_attribute]
+pub fn init_struct(args: TokenStream, input: TokenStream) -> TokenStream {
+    if !args.is_empty() {
+        return Error::new(
+            Span::call_site(),
+            "`#[init_struct]` does not take arguments",
+        )
+        .into_compile_error()
+        .into();
+    }
+    expand_struct(parse_macro_input!(input))
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Returns the value of the field, if it is not taken as a parameter.
+///
+/// This is the case for fields marked with `#[init(default)]` or `#[init(with = "expr")]`.
+fn init_value(field: &syn::Field) -> syn::Result<Option<TokenStream2>> {
+    let mut value = None;
+    for attr in field
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("init"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("default") {
+                value = Some(quote!(::core::default::Default::default()));
+                Ok(())
+            } else if meta.path.is_ident("with") {
+                let expr: syn::LitStr = meta.value()?.parse()?;
+                value = Some(expr.parse()?);
+                Ok(())
+            } else {
+                Err(meta.error("expected `default` or `with`"))
+            }
+        })?;
+    }
+    Ok(value)
+}
+
+/// Returns `true` if the type is `PhantomData` or `PhantomPinned`, those are initialized with
+/// [`Default::default()`] without the need of `#[init(default)]`.
+///
+/// Only the bare names and the paths in `core::marker` and `std::marker` are recognized, other
+/// types with the same name still need to be passed as a parameter.
+fn is_phantom(ty: &syn::Type) -> bool {
+    match ty {
+        syn::Type::Path(path) if path.qself.is_none() => {
+            let segments = path
+                .path
+                .segments
+                .iter()
+                .map(|seg| seg.ident.to_string())
+                .collect::<Vec<_>>();
+            let segments = segments.iter().map(String::as_str).collect::<Vec<_>>();
+            matches!(
+                segments.as_slice(),
+                ["PhantomData" | "PhantomPinned"]
+                    | ["core" | "std", "marker", "PhantomData" | "PhantomPinned"]
+            )
+        }
+        _ => false,
+    }
+}
+
+fn expand(input: DeriveInput, pinned: bool) -> syn::Result<TokenStream2> {
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "init-functions can only be derived for structs",
+            ))
+        }
+    };
+    init_fn(&input.ident, &input.vis, &input.generics, fields, pinned)
+}
+
+fn expand_struct(mut item: DeriveInput) -> syn::Result<TokenStream2> {
+    let fields = match &mut item.data {
+        Data::Struct(data) => &mut data.fields,
+        _ => {
+            return Err(Error::new_spanned(
+                &item.ident,
+                "`#[init_struct]` can only be used on structs",
+            ))
+        }
+    };
+    let pinned = fields
+        .iter()
+        .any(|field| field.attrs.iter().any(|attr| attr.path().is_ident("pin")));
+    let init_fn = init_fn(&item.ident, &item.vis, &item.generics, fields, pinned)?;
+    for field in fields.iter_mut() {
+        field.attrs.retain(|attr| !attr.path().is_ident("init"));
+    }
+    let fields = &*fields;
+    if !pinned {
+        return Ok(quote!(#item #init_fn));
+    }
+
+    // `pin_data!` only accepts the names of the type parameters, their bounds are moved into the
+    // where clause.
+    let mut decls = Vec::new();
+    let mut predicates = Vec::new();
+    for param in &item.generics.params {
+        match param {
+            GenericParam::Lifetime(lt) => decls.push(quote!(#lt)),
+            GenericParam::Type(ty) => {
+                if let Some(default) = &ty.default {
+                    return Err(Error::new_spanned(default, "defaults are not supported"));
+                }
+                let ident = &ty.ident;
+                let bounds = &ty.bounds;
+                decls.push(quote!(#ident));
+                if !bounds.is_empty() {
+                    predicates.push(quote!(#ident: #bounds));
+                }
+            }
+            GenericParam::Const(c) => {
+                if let Some(default) = &c.default {
+                    return Err(Error::new_spanned(default, "defaults are not supported"));
+                }
+                let ident = &c.ident;
+                let ty = &c.ty;
+                decls.push(quote!(const #ident: #ty));
+            }
+        }
+    }
+    if let Some(where_clause) = &item.generics.where_clause {
+        predicates.extend(where_clause.predicates.iter().map(|pred| quote!(#pred)));
+    }
+    let generics = if decls.is_empty() {
+        quote!()
+    } else {
+        quote!(<#(#decls),*>)
+    };
+    let where_clause = if predicates.is_empty() {
+        quote!()
+    } else {
+        quote!(where #(#predicates),*)
+    };
+    let hash = Punct::new('#', Spacing::Alone);
+    let body = fields.iter().map(|field| {
+        let pin = field
+            .attrs
+            .iter()
+            .any(|attr| attr.path().is_ident("pin"))
+            .then(|| quote!(#hash pin));
+        let attrs = field
+            .attrs
+            .iter()
+            .filter(|attr| !attr.path().is_ident("pin"));
+        let vis = &field.vis;
+        let ty = &field.ty;
+        match &field.ident {
+            Some(ident) => quote!(#pin #(#attrs)* #vis #ident: #ty),
+            None => quote!(#pin #(#attrs)* #vis #ty),
+        }
+    });
+    let attrs = &item.attrs;
+    let vis = &item.vis;
+    let name = &item.ident;
+    let body = match fields {
+        Fields::Named(_) => quote!(#where_clause { #(#body),* }),
+        Fields::Unnamed(_) => quote!((#(#body),*) #where_clause;),
+        Fields::Unit => unreachable!("unit structs have no pinned fields"),
+    };
+    Ok(quote! {
+        ::simple_safe_init::pin_data! {
+            #(#attrs)*
+            #vis struct #name #generics #body
+        }
+        #init_fn
+    })
+}
+
+/// Generates the init-function for a struct with the given fields.
+fn init_fn(
+    name: &Ident,
+    vis: &Visibility,
+    generics: &Generics,
+    fields: &Fields,
+    pinned: bool,
+) -> syn::Result<TokenStream2> {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    // the guard parameter must not collide with a type parameter of the struct.
+    let guard = (0usize..)
+        .map(|i| match i {
+            0 => format_ident!("G"),
+            i => format_ident!("G{}", i),
+        })
+        .find(|guard| {
+            !generics.params.iter().any(|param| match param {
+                GenericParam::Type(ty) => ty.ident == *guard,
+                GenericParam::Const(c) => c.ident == *guard,
+                GenericParam::Lifetime(_) => false,
+            })
+        })
+        .unwrap();
+    // mixed site hygiene, so fields named `this` do not collide with it.
+    let this = Ident::new("this", Span::mixed_site());
+
+    let mut params = Vec::new();
+    let mut inits = Vec::new();
+    for (i, field) in fields.iter().enumerate() {
+        let member = match &field.ident {
+            Some(ident) => quote!(#ident),
+            None => {
+                let index = Index::from(i);
+                quote!(#index)
+            }
+        };
+        let value = if let Some(value) = init_value(field)? {
+            value
+        } else if is_phantom(&field.ty) {
+            quote!(::core::default::Default::default())
+        } else {
+            let param = field
+                .ident
+                .clone()
+                .unwrap_or_else(|| format_ident!("_{}", i));
+            let ty = &field.ty;
+            params.push(quote!(#param: #ty));
+            quote!(#param)
+        };
+        inits.push(quote!(.#member = #value;));
+    }
+
+    let (func, pointer) = if pinned {
+        (quote!(pin_init), quote!(PinInitMe))
+    } else {
+        (quote!(init), quote!(InitMe))
+    };
+    let doc = format!("Initializes all fields of [`{}`].", name);
+    Ok(quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            #[doc = #doc]
+            #[allow(clippy::too_many_arguments)]
+            #vis fn #func<#guard: ::simple_safe_init::Guard>(
+                #this: ::simple_safe_init::#pointer<'_, Self, #guard>,
+                #(#params),*
+            ) -> ::simple_safe_init::InitProof<(), #guard> {
+                ::simple_safe_init::init! { #this => Self { #(#inits)* } }
+            }
+        }
+    })
+}
@@ -1,12 +1,22 @@
-#![feature(allocator_api, new_uninit, never_type)]
+#![cfg_attr(nightly, feature(allocator_api))]
 
 use core::pin::Pin;
-use simple_safe_init::*;
-use std::alloc::AllocError;
+use simple_safe_init::{place::AllocError, *};
 
 mod buf {
     use super::*;
-    use std::alloc::AllocError;
+    use core::mem::MaybeUninit;
+
+    // allocation failures can only be handled on nightly, on stable they abort.
+    #[cfg(nightly)]
+    fn new_zeroed<T>() -> Result<Box<MaybeUninit<T>>, AllocError> {
+        Box::try_new_zeroed()
+    }
+
+    #[cfg(not(nightly))]
+    fn new_zeroed<T>() -> Result<Box<MaybeUninit<T>>, AllocError> {
+        Ok(Box::new_zeroed())
+    }
 
     pin_data! {
         pub struct Buffers {
@@ -21,7 +31,7 @@ mod buf {
             this: PinInitMe<'_, Self, G>,
         ) -> Result<InitProof<(), G>, AllocError> {
             Ok(init! { this => Self {
-                let buf = Box::try_new_zeroed()?;
+                let buf = new_zeroed()?;
                 let buf = unsafe {
                     // SAFETY: Buffer has been zeroed
                     buf.assume_init()
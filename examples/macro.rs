@@ -1,4 +1,3 @@
-#![feature(generic_associated_types)]
 use core::mem::MaybeUninit;
 use simple_safe_init::*;
 
@@ -1,4 +1,3 @@
-#![feature(generic_associated_types)]
 use core::{cell::UnsafeCell, marker::PhantomPinned, mem::MaybeUninit};
 use simple_safe_init::*;
 
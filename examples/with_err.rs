@@ -1,6 +1,5 @@
-#![feature(allocator_api)]
 use core::pin::Pin;
-use simple_safe_init::*;
+use simple_safe_init::{place::AllocError, *};
 mod structs {
     use core::{marker::PhantomPinned, pin::Pin};
     use simple_safe_init::*;
@@ -35,9 +34,9 @@ mod structs {
     }
 }
 use structs::MyPinnedStruct;
-fn main() -> Result<(), std::alloc::AllocError> {
+fn main() -> Result<(), AllocError> {
     let mut my_struct =
         init!(@MyPinnedStruct::init(Pin<Box<MyPinnedStruct>>, "Hello World".to_owned()))?;
     my_struct.as_mut().print_info();
-    Ok::<(), std::alloc::AllocError>(())
+    Ok::<(), AllocError>(())
 }
@@ -0,0 +1,66 @@
+//! Init-functions using [serde](https://serde.rs) deserializers.
+//!
+//! The value is deserialized and then written into the place, so it is not constructed in place.
+//! This still allows fields to be initialized by a [`Deserializer`] inside of [`init!`]:
+//! ```rust
+//! use core::mem::MaybeUninit;
+//! use serde::de::{value::Error, IntoDeserializer};
+//! use simple_safe_init::*;
+//!
+//! pin_data! {
+//!     struct Config {
+//!         name: String,
+//!         #pin
+//!         limit: u32,
+//!     }
+//! }
+//!
+//! let config = Box::pin(MaybeUninit::uninit());
+//! let config = (|| {
+//!     Ok::<_, Error>(init! { config => Config {
+//!         de::deserialize(.name, "server".into_deserializer())?;
+//!         de::deserialize_pinned(.limit, 16u32.into_deserializer())?;
+//!     }})
+//! })()
+//! .unwrap();
+//! assert_eq!((&*config.name, config.limit), ("server", 16));
+//! ```
+//!
+//! [`init!`]: crate::init!
+
+use crate::{Guard, InitMe, InitProof, PinInitMe};
+use serde::{Deserialize, Deserializer};
+
+/// Deserializes a `T` from `des` and initializes `this` with it.
+///
+/// # Errors
+///
+/// Returns the error of the deserializer, `this` is not initialized in that case.
+pub fn deserialize<'de, T, D, G>(
+    this: InitMe<'_, T, G>,
+    des: D,
+) -> Result<InitProof<(), G>, D::Error>
+where
+    T: Deserialize<'de>,
+    D: Deserializer<'de>,
+    G: Guard,
+{
+    Ok(this.write(T::deserialize(des)?))
+}
+
+/// Deserializes a `T` from `des` and initializes the pinned `this` with it.
+///
+/// # Errors
+///
+/// Returns the error of the deserializer, `this` is not initialized in that case.
+pub fn deserialize_pinned<'de, T, D, G>(
+    this: PinInitMe<'_, T, G>,
+    des: D,
+) -> Result<InitProof<(), G>, D::Error>
+where
+    T: Deserialize<'de>,
+    D: Deserializer<'de>,
+    G: Guard,
+{
+    Ok(this.write(T::deserialize(des)?))
+}
@@ -263,8 +263,9 @@
 //! When you have initialization that can fail, for example you need to allocate a field, then you
 //! can use the following syntax:
 //!
-//! ```rust
-//! #![feature(allocator_api, new_uninit)]
+#![cfg_attr(nightly, doc = "```rust")]
+#![cfg_attr(not(nightly), doc = "```rust,ignore")]
+//! #![feature(allocator_api)]
 //! use core::{mem::MaybeUninit, pin::Pin};
 //! use simple_safe_init::*;
 //! use std::alloc::AllocError;
@@ -306,8 +307,9 @@
 //!
 //! The same syntax is also supported in the initializer:
 //!
-//! ```rust
-//! # #![feature(allocator_api, new_uninit)]
+#![cfg_attr(nightly, doc = "```rust")]
+#![cfg_attr(not(nightly), doc = "```rust,ignore")]
+//! # #![feature(allocator_api)]
 //! # use core::{mem::MaybeUninit, pin::Pin};
 //! # use simple_safe_init::*;
 //! # use std::alloc::AllocError;
@@ -365,6 +367,10 @@
 //!     // `expr` is any rust expression:
 //!     .$field = $expr;
 //!
+//!     // `expr` is any rust expression that needs `unsafe` to evaluate, the block can only
+//!     // contain this single assignment:
+//!     unsafe { .$field = $expr; }
+//!
 //!     // `$func` is an init function with the correct type for `$field`
 //!     // (pay attention to the right pin status: if $field is structurally pinned, then
 //!     // $func needs to take a `PinInitMe`, otherwise an `InitMe`), `$param`
@@ -402,7 +408,36 @@
 //!
 //!     // `$func` and `$param` are the same as before and `$pat` is any rust pattern:
 //!     ~let $pat = $func(.$field, $($param),*).await;
+//!
+//!     // `$func` is an init function initializing two fields at once, it returns an
+//!     // `InitProof` with the guard `(G1, G2)` (see `InitProof::zip`). The binding with `$pat`
+//!     // and the `?` are optional:
+//!     ~let $pat = $func(.$field1, .$field2, $($param),*)?;
+//! }};
+//! ```
+//!
+//! `~let` binds the value returned with [`InitProof::ret`] (the `T` in `InitProof<T, G>`). The
+//! binding can be used by all following statements, so the result of initializing one field can
+//! be used to initialize another one:
+//! ```rust
+//! # use core::mem::MaybeUninit;
+//! # use simple_safe_init::*;
+//! pin_data! {
+//!     struct Pair {
+//!         first: Vec<u8>,
+//!         len: usize,
+//!     }
+//! }
+//!
+//! fn init_first<G: Guard>(first: InitMe<'_, Vec<u8>, G>, data: &[u8]) -> InitProof<usize, G> {
+//!     first.write(data.to_vec()).ret(data.len())
+//! }
+//!
+//! let pair = init! { Box::new(MaybeUninit::uninit()) => Pair {
+//!     ~let len = init_first(.first, b"hello");
+//!     .len = len;
 //! }};
+//! assert_eq!(pair.len, 5);
 //! ```
 //!
 //! You can use `@$type` for types implementing the [`AllocablePlace`] trait to speed up the allocation:
@@ -427,7 +462,6 @@
 //!
 //! You can also specify the type to avoid manual allocation:
 //! ```rust
-//! # #![feature(allocator_api)]
 //! # use core::{mem::MaybeUninit, pin::Pin, marker::PhantomPinned};
 //! # use simple_safe_init::*;
 //! # mod structs {
@@ -464,7 +498,7 @@
 //! # }
 //! # use structs::MyPinnedStruct;
 //! let mut my_struct = init!(@MyPinnedStruct::init(Pin<Box<MyPinnedStruct>>, "Hello World".to_owned()))?;
-//! # Ok::<(), std::alloc::AllocError>(())
+//! # Ok::<(), place::AllocError>(())
 //! ```
 //!
 //!
@@ -472,6 +506,14 @@
 //! ## Smart Pointer Support
 //! See [`PartialInitPlace`].
 //!
+//! ## Stable Rust
+//! This library also compiles on stable Rust, this is detected automatically. Allocations then
+//! abort on failure like [`Box::new`], so [`place::AllocError`] becomes [`Infallible`] and `!` is
+//! not a [`PartialInitPlace`]. The `docsrs` feature has no effect.
+//!
+//! [`Box::new`]: alloc::boxed::Box::new
+//! [`Infallible`]: core::convert::Infallible
+//!
 //! ## How does [`init!`] work?
 //! This section is intended for readers trying to understand the inner workings of this library.
 //! If you only intend to use the library you do not need to read this section.
@@ -482,10 +524,12 @@
 //! - [`PartialInitPlace`] marks types that can be used as memory locations for initialization,
 //! - [`PinnedPlace`] marks [`PartialInitPlace`]s which have stable addresses for the duration of
 //! their existence,
+//! - [`OwnedPlace`] marks [`PartialInitPlace`]s which own their pointee, only these can be pinned,
 //! - [`InitPointer<T, G>`] marks init pointers and ensures users can only set an init pointer
 //! from this library as [`PartialInitPlace::InitMe`],
 //! - [`Guard`] marks guard parameters used to ensure validity of initialization,
 //! - [`AllocablePlace`] marks [`PartialInitPlace`]s which can be allocated,
+//! - [`LenAllocablePlace`] marks slice [`PartialInitPlace`]s which can be allocated,
 //! - [`___PinData`] is implemented by the [`pin_data!`] macro, it is used to uphold
 //! the correct pinning invariants for each of the fields.
 //!
@@ -520,11 +564,10 @@
 //! [`MaybeUninit::uninit()`]: core::mem::MaybeUninit::uninit
 
 #![no_std]
-#![cfg_attr(feature = "alloc", feature(new_uninit))]
-#![cfg_attr(feature = "alloc", feature(allocator_api))]
-#![cfg_attr(feature = "alloc", feature(get_mut_unchecked))]
-#![feature(generic_associated_types, never_type)]
-#![cfg_attr(feature = "docsrs", feature(doc_cfg))]
+#![cfg_attr(all(feature = "alloc", nightly), feature(allocator_api))]
+#![cfg_attr(all(feature = "alloc", nightly), feature(get_mut_unchecked))]
+#![cfg_attr(nightly, feature(never_type))]
+#![cfg_attr(all(feature = "docsrs", nightly), feature(doc_cfg))]
 #![forbid(unsafe_op_in_unsafe_fn)]
 #![warn(missing_docs)]
 #![warn(rustdoc::missing_crate_level_docs)]
@@ -536,18 +579,35 @@ extern crate alloc;
 mod macros;
 pub mod place;
 // TODO change to docsrs
-#[cfg_attr(feature = "docsrs", doc(cfg(feature = "alloc")))]
+#[cfg_attr(all(feature = "docsrs", nightly), doc(cfg(feature = "alloc")))]
 #[cfg(feature = "alloc")]
 pub mod unique;
 
+#[cfg_attr(all(feature = "docsrs", nightly), doc(cfg(feature = "serde")))]
+#[cfg(feature = "serde")]
+pub mod de;
+
 mod tests;
 
+#[cfg_attr(all(feature = "docsrs", nightly), doc(cfg(feature = "derive")))]
+#[cfg(feature = "derive")]
+pub use simple_safe_init_derive::{init_struct, Init, PinInit};
+
 use core::{
     fmt::{self, Formatter, Pointer},
     marker::PhantomData,
 };
 use place::*;
 
+/// The maximum size in bytes of a type initialized by [`stack_init!`], when the `stack_limit`
+/// feature is enabled.
+///
+/// Larger types should be initialized on the heap instead, e.g. with `init!(@... (Pin<Box<T>>))`.
+///
+/// Defaults to 4096 and can be changed by setting the `SIMPLE_SAFE_INIT_MAX_STACK_INIT_SIZE`
+/// environment variable at build time.
+pub const MAX_STACK_INIT_SIZE: usize = include!(concat!(env!("OUT_DIR"), "/max_stack_init_size"));
+
 mod sealed {
     use super::*;
     pub trait Sealed {}
@@ -580,6 +640,7 @@ pub trait InitPointer<'a, T: ?Sized, G: Guard>: sealed::Sealed + Pointer {
 /// # Generic Arguments
 /// - `T` is the type that this pointer points to and that needs to be initialized,
 /// - `G` is a [`Guard`] parameter.
+#[must_use = "the pointee is not initialized until this is passed to an init-function or `init!`"]
 pub struct InitMe<'a, T: ?Sized, G: Guard> {
     ptr: *mut T,
     // We need the correct variance, so we only accept the exact type for `G`. `T` and `'a` should
@@ -716,6 +777,71 @@ impl<'a, T: ?Sized, G: Guard> InitMe<'a, T, G> {
     pub fn as_mut_ptr(&mut self) -> *mut T {
         self.ptr
     }
+
+    /// Convert into a [`PinInitMe`], pinning has no effect on `T: Unpin`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use core::mem::MaybeUninit;
+    /// use simple_safe_init::*;
+    ///
+    /// fn init_pinned<G: Guard>(this: PinInitMe<'_, u32, G>) -> InitProof<(), G> {
+    ///     this.write(42)
+    /// }
+    ///
+    /// fn init<G: Guard>(this: InitMe<'_, u32, G>) -> InitProof<(), G> {
+    ///     init_pinned(this.into_pinned())
+    /// }
+    ///
+    /// let value = init!(init(Box::new(MaybeUninit::uninit())));
+    /// assert_eq!(*value, *init!(init_pinned(Box::pin(MaybeUninit::uninit()))));
+    /// ```
+    pub fn into_pinned(self) -> PinInitMe<'a, T, G>
+    where
+        T: Unpin,
+    {
+        PinInitMe {
+            ptr: self.ptr,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<'a, T, G: Guard> InitMe<'a, [T], G> {
+    /// Returns the number of elements of the slice.
+    pub fn len(&self) -> usize {
+        self.ptr.len()
+    }
+
+    /// Returns `true` if the slice has a length of 0.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Initializes every element of the slice with the value returned by `f` for its index.
+    ///
+    /// If `f` panics, the elements initialized before are leaked.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use simple_safe_init::*;
+    ///
+    /// let squares: Box<[usize]> = init_with_len!(@InitMe::write_each(Box<[usize]>, 4, |i| i * i)).unwrap();
+    /// assert_eq!(*squares, [0, 1, 4, 9]);
+    /// ```
+    pub fn write_each<F: FnMut(usize) -> T>(self, mut f: F) -> InitProof<(), G> {
+        let start = self.ptr.cast::<T>();
+        for i in 0..self.len() {
+            unsafe {
+                // SAFETY: We always create InitMe with a valid pointer, `i` is in bounds
+                start.add(i).write(f(i));
+            }
+        }
+        InitProof {
+            value: (),
+            _phantom: PhantomData,
+        }
+    }
 }
 
 unsafe impl<'a, T: ?Sized, G: Guard> PartialInitPlace for InitMe<'a, T, G> {
@@ -748,6 +874,7 @@ unsafe impl<'a, T: ?Sized, G: Guard> PartialInitPlace for InitMe<'a, T, G> {
 /// # Generic Arguments
 /// `T` is the type that this pointer points to and that needs to be initialized.
 /// `G` is a [`Guard`] parameter.
+#[must_use = "the pointee is not initialized until this is passed to an init-function or `init!`"]
 pub struct PinInitMe<'a, T: ?Sized, G: Guard> {
     ptr: *mut T,
     // We need the correct variance, so we only accept the exact type for `G`. `T` and `'a` should
@@ -894,6 +1021,34 @@ impl<'a, T: ?Sized, G: Guard> PinInitMe<'a, T, G> {
     pub fn as_mut_ptr(&mut self) -> *mut T {
         self.ptr
     }
+
+    /// Convert into an [`InitMe`], pinning has no effect on `T: Unpin`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use core::mem::MaybeUninit;
+    /// use simple_safe_init::*;
+    ///
+    /// fn init<G: Guard>(this: InitMe<'_, String, G>) -> InitProof<(), G> {
+    ///     this.write("Hello".to_owned())
+    /// }
+    ///
+    /// fn init_pinned<G: Guard>(this: PinInitMe<'_, String, G>) -> InitProof<(), G> {
+    ///     init(this.into_unpinned())
+    /// }
+    ///
+    /// let value = init!(init_pinned(Box::pin(MaybeUninit::uninit())));
+    /// assert_eq!(*value, *init!(init(Box::new(MaybeUninit::uninit()))));
+    /// ```
+    pub fn into_unpinned(self) -> InitMe<'a, T, G>
+    where
+        T: Unpin,
+    {
+        InitMe {
+            ptr: self.ptr,
+            _phantom: PhantomData,
+        }
+    }
 }
 
 unsafe impl<'a, T: ?Sized, G: Guard> PartialInitPlace for PinInitMe<'a, T, G> {
@@ -925,6 +1080,7 @@ unsafe impl<'a, T: ?Sized, G: Guard> PinnedPlace for PinInitMe<'a, T, G> {}
 /// The first parameter `T` is a wrapped value that was the normal return value of the function.
 ///
 /// The second parameter `G` is a [`Guard`] parameter.
+#[must_use = "initialization is only complete when the `InitProof` is returned"]
 pub struct InitProof<T, G: Guard> {
     value: T,
     // correct invariance, we only accept the exact type G
@@ -942,6 +1098,63 @@ impl<T, G: Guard> InitProof<T, G> {
     pub fn ___unwrap(self, _guard: G) -> T {
         self.value
     }
+
+    /// Transform the returned value.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use core::mem::MaybeUninit;
+    /// use simple_safe_init::*;
+    /// pin_data! {
+    ///     #[derive(Debug)]
+    ///     struct Count {
+    ///         inner: usize,
+    ///     }
+    /// }
+    ///
+    /// fn init_count<G: Guard>(this: InitMe<'_, usize, G>) -> InitProof<usize, G> {
+    ///     this.write(42).ret(42)
+    /// }
+    ///
+    /// fn init_count_double<G: Guard>(this: InitMe<'_, usize, G>) -> InitProof<usize, G> {
+    ///     init_count(this).map(|val| val * 2)
+    /// }
+    ///
+    /// let count = Box::new(MaybeUninit::uninit());
+    /// let count = init! { count => Count {
+    ///     ~let double = init_count_double(.inner);
+    ///     assert_eq!(double, 84);
+    /// }};
+    /// println!("{count:?}");
+    /// ```
+    pub fn map<U, F: FnOnce(T) -> U>(self, f: F) -> InitProof<U, G> {
+        InitProof {
+            value: f(self.value),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Combine two proofs into a single one, proving that both values were initialized.
+    ///
+    /// The returned proof uses the guard `(G, G2)`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use simple_safe_init::*;
+    ///
+    /// fn init_both<G1: Guard, G2: Guard>(
+    ///     a: InitMe<'_, u32, G1>,
+    ///     b: InitMe<'_, u64, G2>,
+    /// ) -> InitProof<((), ()), (G1, G2)> {
+    ///     a.write(1).zip(b.write(2))
+    /// }
+    /// ```
+    pub fn zip<U, G2: Guard>(self, other: InitProof<U, G2>) -> InitProof<(T, U), (G, G2)> {
+        InitProof {
+            value: (self.value, other.value),
+            _phantom: PhantomData,
+        }
+    }
 }
 
 impl<G: Guard> InitProof<(), G> {
@@ -987,6 +1200,44 @@ impl<G: Guard> InitProof<(), G> {
             _phantom: PhantomData,
         }
     }
+
+    /// Combine two proofs without a value into a single one, see [`InitProof::zip`].
+    ///
+    /// # Examples
+    /// ```rust
+    /// use simple_safe_init::*;
+    ///
+    /// fn init_name<G: Guard>(name: InitMe<'_, String, G>) -> InitProof<(), G> {
+    ///     name.write("foo".to_owned())
+    /// }
+    ///
+    /// fn init_id<G: Guard>(id: InitMe<'_, u64, G>) -> InitProof<(), G> {
+    ///     id.write(1)
+    /// }
+    ///
+    /// fn init_both<G1: Guard, G2: Guard>(
+    ///     name: InitMe<'_, String, G1>,
+    ///     id: InitMe<'_, u64, G2>,
+    /// ) -> InitProof<(), (G1, G2)> {
+    ///     init_name(name).chain(init_id(id))
+    /// }
+    ///
+    /// pin_data! {
+    ///     struct User {
+    ///         name: String,
+    ///         id: u64,
+    ///     }
+    /// }
+    ///
+    /// let user = Box::new(core::mem::MaybeUninit::uninit());
+    /// let user = init! { user => User {
+    ///     init_both(.name, .id);
+    /// }};
+    /// assert_eq!((&*user.name, user.id), ("foo", 1));
+    /// ```
+    pub fn chain<G2: Guard>(self, other: InitProof<(), G2>) -> InitProof<(), (G, G2)> {
+        self.zip(other).map(|((), ())| ())
+    }
 }
 
 /// Marker trait used to mark guard parameters.
@@ -1004,6 +1255,9 @@ impl<G: Guard> InitProof<(), G> {
 /// - [`InitMe::assume_init`] / [`PinInitMe::assume_init`] (unsafely assume initialization, this of
 /// course needs to be validated manually and used very carefully!)
 /// - [`init!`] used on [`InitMe<T, G>`] / [`PinInitMe<T, G>`]
+/// - [`InitProof::map`] / [`InitProof::ret`] on an existing proof with the same guard `G`
+/// - [`InitProof::zip`] / [`InitProof::chain`] on two proofs with the guards `G1` and `G2`, the
+///   result has the guard `(G1, G2)`
 ///
 ///
 ///
@@ -1026,3 +1280,6 @@ impl<G: Guard> InitProof<(), G> {
 ///
 /// [`InitProof<(), G>`]: InitProof
 pub unsafe trait Guard {}
+
+// SAFETY: a tuple of guards is only accessible where both guards are accessible.
+unsafe impl<G1: Guard, G2: Guard> Guard for (G1, G2) {}
@@ -77,13 +77,22 @@ macro_rules! init {
 #[cfg(not(doc))]
 #[macro_export]
 macro_rules! init {
+    // initialize an arbitrary expression manually (init each field), the type has lifetime
+    // arguments. A lifetime can also start a type, so they cannot be parsed together with the
+    // other generic arguments. They can be inferred, so drop them one by one.
+    ($var:expr => $struct:ident < $life:lifetime , $($rest:tt)*) => {
+        $crate::init!($var => $struct < $($rest)*)
+    };
+    ($var:expr => $struct:ident < $life:lifetime > $($rest:tt)*) => {
+        $crate::init!($var => $struct $($rest)*)
+    };
     // initialize an arbitrary expression manually (init each field).
-    ($var:expr => $struct:ident $(<$($generic:ty),*>)? { $($tail:tt)* }) => {
+    ($var:expr => $struct:ident $(<$($($lit:literal)? $({$($block:tt)*})? $($generic:ty)?),*>)? { $($tail:tt)* }) => {
         match $var {
             mut var => {
                 fn no_warn<___T>(_: &mut ___T) {}
                 no_warn(&mut var);
-                $crate::init!(@@inner(var, _is_pinned, (), ($struct $(<$($generic),*>)?)) $($tail)*);
+                $crate::init!(@@inner(var, _is_pinned, (), ($struct [$($($($lit)? $({$($block)*})? $($generic)?,)*)?])) $($tail)*);
                 unsafe {
                     // SAFETY: The pointee of `var` has been fully initialized, if this part is
                     // reachable and no compile error exist.
@@ -145,13 +154,21 @@ macro_rules! init {
             Err(e) => Err(e),
         }.map(<$var as $crate::place::AllocablePlace>::after_init)
     };
+    // initialize a specific AllocablePlace manually (init each field), the type has lifetime
+    // arguments.
+    (@$var:ty => $struct:ident < $life:lifetime , $($rest:tt)*) => {
+        $crate::init!(@$var => $struct < $($rest)*)
+    };
+    (@$var:ty => $struct:ident < $life:lifetime > $($rest:tt)*) => {
+        $crate::init!(@$var => $struct $($rest)*)
+    };
     // initialize a specific AllocablePlace manually (init each field).
-    (@$var:ty => $struct:ident $(<$($generic:ty),*>)? { $($tail:tt)* }) => {
+    (@$var:ty => $struct:ident $(<$($($lit:literal)? $({$($block:tt)*})? $($generic:ty)?),*>)? { $($tail:tt)* }) => {
         match <$var as $crate::place::AllocablePlace>::allocate() {
             Ok(mut var) => {
                 fn no_warn<___T>(_: &mut ___T) {}
                 no_warn(&mut var);
-                $crate::init!(@@inner(var, _is_pinned, (), ($struct $(<$($generic),*>)?)) $($tail)*);
+                $crate::init!(@@inner(var, _is_pinned, (), ($struct [$($($($lit)? $({$($block)*})? $($generic)?,)*)?])) $($tail)*);
                 let res = unsafe {
                     // SAFETY: The pointee of `var` has been fully initialized, if this part is
                     // reachable and no compile error exist.
@@ -173,17 +190,17 @@ macro_rules! init {
     // mentioned. If one is missing or a duplicate, the compiler will complain.
     // We do this inside of a closure, because we do not want to really create this struct. Also,
     // the values of the fields are `conjure()` so we never actually produce a value.
-    (@@inner($var:ident, $pin:ident, ($($inner:tt)*), ($name:ident $(<$($generic:ty),*>)?))) => {
+    (@@inner($var:ident, $pin:ident, ($($inner:tt)*), ($name:ident [$($generic:tt)*]))) => {
         #[allow(unreachable_code, clippy::diverging_sub_expression)]
         let ___check_all_init = || {
-            let _struct: $name $(<$($generic),*>)? = $name {
+            let _struct: $name<$($generic)*> = $name {
                 $($inner)*
             };
         };
     };
     // a normal assignment, use raw pointers to set the value.
-    (@@inner($var:ident, $pin:ident, ($($inner:tt)*), ($name:ident $(<$($generic:ty),*>)?))
-        .$field:ident = $val:expr;
+    (@@inner($var:ident, $pin:ident, ($($inner:tt)*), ($name:ident [$($generic:tt)*]))
+        .$field:tt = $val:expr;
         $($tail:tt)*
     ) => {
         match $val {
@@ -192,111 +209,155 @@ macro_rules! init {
                 // memory. we only use ptr::write, which is allowed
                 ::core::ptr::write(
                     ::core::ptr::addr_of_mut!(
-                        (*$crate::place::PartialInitPlace::___as_mut_ptr(&mut $var, &|_: &$name $(<$($generic),*>)?|  {})).$field
+                        (*$crate::place::PartialInitPlace::___as_mut_ptr(&mut $var, &|_: &$name<$($generic)*>|  {})).$field
                     ),
                     val
                 );
             }
         }
-        $crate::init!(@@inner($var, $pin, ($($inner)* $field: ::core::panic!(),), ($name $(<$($generic),*>)?)) $($tail)*);
+        $crate::init!(@@inner($var, $pin, ($($inner)* $field: ::core::panic!(),), ($name [$($generic)*])) $($tail)*);
+    };
+
+    // an assignment with an unsafe value, the `;` after the block is optional.
+    (@@inner($var:ident, $pin:ident, ($($inner:tt)*), ($name:ident [$($generic:tt)*]))
+        unsafe { .$field:tt = $val:expr $(;)? };
+        $($tail:tt)*
+    ) => {
+        $crate::init!(@@inner($var, $pin, ($($inner)*), ($name [$($generic)*]))
+            .$field = unsafe {
+                // SAFETY: macro-caller guarantees this is sound
+                $val
+            };
+            $($tail)*
+        );
+    };
+    (@@inner($var:ident, $pin:ident, ($($inner:tt)*), ($name:ident [$($generic:tt)*]))
+        unsafe { .$field:tt = $val:expr $(;)? }
+        $($tail:tt)*
+    ) => {
+        $crate::init!(@@inner($var, $pin, ($($inner)*), ($name [$($generic)*]))
+            .$field = unsafe {
+                // SAFETY: macro-caller guarantees this is sound
+                $val
+            };
+            $($tail)*
+        );
     };
 
+    // a function call initializing two fields at once, the function returns an `InitProof` with
+    // the guard `(G1, G2)`. This needs to come before the single field version, because that one
+    // would also match.
+    (@@inner($var:ident, $pin:ident, ($($inner:tt)*), ($name:ident [$($generic:tt)*]))
+        $(~let $binding:pat = )?$func:ident $(:: $(<$($args:ty),*$(,)?>::)? $path:ident)*(.$field1:tt, .$field2:tt $($rest:tt)*);
+        $($tail:tt)*
+    ) => {
+        $crate::init!(@@init_call2($var, $name [$($generic)*], ($field1, field_place1), ($field2, field_place2), ($func $(:: $(<$($args),*>::)? $path)*(field_place1, field_place2 $($rest)*)), $($binding)?));
+        $crate::init!(@@inner($var, $pin, ($($inner)* $field1: ::core::panic!(), $field2: ::core::panic!(),), ($name [$($generic)*])) $($tail)*);
+    };
     // a function call initializing a single field, we cannot use the `path` meta-variable type,
     // because `(` is not allowed after that :(
-    (@@inner($var:ident, $pin:ident, ($($inner:tt)*), ($name:ident $(<$($generic:ty),*>)?))
-        $(~let $binding:pat = )?$func:ident $(:: $(<$($args:ty),*$(,)?>::)? $path:ident)*(.$field:ident $($rest:tt)*);
+    (@@inner($var:ident, $pin:ident, ($($inner:tt)*), ($name:ident [$($generic:tt)*]))
+        $(~let $binding:pat = )?$func:ident $(:: $(<$($args:ty),*$(,)?>::)? $path:ident)*(.$field:tt $($rest:tt)*);
         $($tail:tt)*
     ) => {
-        $crate::init!(@@init_call($var, $name $(<$($generic),*>)?, $field, field_place, ($func $(:: $(<$($args),*>::)? $path)*(field_place $($rest)*)), $($binding)?));
-        $crate::init!(@@inner($var, $pin, ($($inner)* $field: ::core::panic!(),), ($name $(<$($generic),*>)?)) $($tail)*);
+        $crate::init!(@@init_call($var, $name [$($generic)*], $field, field_place, ($func $(:: $(<$($args),*>::)? $path)*(field_place $($rest)*)), $($binding)?));
+        $crate::init!(@@inner($var, $pin, ($($inner)* $field: ::core::panic!(),), ($name [$($generic)*])) $($tail)*);
     };
     // an unsafe function initializing a single field.
-    (@@inner($var:ident, $pin:ident, ($($inner:tt)*), ($name:ident $(<$($generic:ty),*>)?))
-        $(~let $binding:pat = )?unsafe { $func:ident $(:: $(<$($args:ty),*$(,)?>::)? $path:ident)*(.$field:ident $($rest:tt)*) };
+    (@@inner($var:ident, $pin:ident, ($($inner:tt)*), ($name:ident [$($generic:tt)*]))
+        $(~let $binding:pat = )?unsafe { $func:ident $(:: $(<$($args:ty),*$(,)?>::)? $path:ident)*(.$field:tt $($rest:tt)*) };
         $($tail:tt)*
     ) => {
-        $crate::init!(@@init_call($var, $name $(<$($generic),*>)?, $field, field_place, (unsafe {
+        $crate::init!(@@init_call($var, $name [$($generic)*], $field, field_place, (unsafe {
             // SAFETY: macro-caller guarantees this is sound
             $func $(:: $(<$($args),*>::)? $path)*(field_place $($rest)*) }
         ), $($binding)?));
-        $crate::init!(@@inner($var, $pin, ($($inner)* $field: ::core::panic!(),), ($name $(<$($generic),*>)?)) $($tail)*);
+        $crate::init!(@@inner($var, $pin, ($($inner)* $field: ::core::panic!(),), ($name [$($generic)*])) $($tail)*);
     };
     // a macro call initializing a single field
-    (@@inner($var:ident, $pin:ident, ($($inner:tt)*), ($name:ident $(<$($generic:ty),*>)?))
-        $(~let $binding:pat = )?$func:ident $(:: $(<$($args:ty),*$(,)?>::)? $path:ident)*!(.$field:ident $($rest:tt)*);
+    (@@inner($var:ident, $pin:ident, ($($inner:tt)*), ($name:ident [$($generic:tt)*]))
+        $(~let $binding:pat = )?$func:ident $(:: $(<$($args:ty),*$(,)?>::)? $path:ident)*!(.$field:tt $($rest:tt)*);
         $($tail:tt)*
     ) => {
-        $crate::init!(@@init_call($var, $name $(<$($generic),*>)?, $field, field_place, ($func $(:: $(<$($args),*>::)? $path)*!(field_place $($rest)*)), $($binding)?));
-        $crate::init!(@@inner($var, $pin, ($($inner)* $field: ::core::panic!(),), ($name $(<$($generic),*>)?)) $($tail)*);
+        $crate::init!(@@init_call($var, $name [$($generic)*], $field, field_place, ($func $(:: $(<$($args),*>::)? $path)*!(field_place $($rest)*)), $($binding)?));
+        $crate::init!(@@inner($var, $pin, ($($inner)* $field: ::core::panic!(),), ($name [$($generic)*])) $($tail)*);
     };
     // an async function call initializing a single field
-    (@@inner($var:ident, $pin:ident, ($($inner:tt)*), ($name:ident $(<$($generic:ty),*>)?))
-        $(~let $binding:pat = )?$func:ident $(:: $(<$($args:ty),*$(,)?>::)? $path:ident)*(.$field:ident $($rest:tt)*).await;
+    (@@inner($var:ident, $pin:ident, ($($inner:tt)*), ($name:ident [$($generic:tt)*]))
+        $(~let $binding:pat = )?$func:ident $(:: $(<$($args:ty),*$(,)?>::)? $path:ident)*(.$field:tt $($rest:tt)*).await;
         $($tail:tt)*
     ) => {
-        $crate::init!(@@init_call($var, $name $(<$($generic),*>)?, $field, field_place, ($func $(:: $(<$($args),*>::)? $path)*(field_place $($rest)*).await), $($binding)?));
-        $crate::init!(@@inner($var, $pin, ($($inner)* $field: ::core::panic!(),), ($name $(<$($generic),*>)?)) $($tail)*);
+        $crate::init!(@@init_call($var, $name [$($generic)*], $field, field_place, ($func $(:: $(<$($args),*>::)? $path)*(field_place $($rest)*).await), $($binding)?));
+        $crate::init!(@@inner($var, $pin, ($($inner)* $field: ::core::panic!(),), ($name [$($generic)*])) $($tail)*);
     };
     // an unsafe async function call initializing a single field
-    (@@inner($var:ident, $pin:ident, ($($inner:tt)*), ($name:ident $(<$($generic:ty),*>)?))
-        $(~let $binding:pat = )? unsafe { $func:ident $(:: $(<$($args:ty),*$(,)?>::)? $path:ident)*(.$field:ident $($rest:tt)*).await };
+    (@@inner($var:ident, $pin:ident, ($($inner:tt)*), ($name:ident [$($generic:tt)*]))
+        $(~let $binding:pat = )? unsafe { $func:ident $(:: $(<$($args:ty),*$(,)?>::)? $path:ident)*(.$field:tt $($rest:tt)*).await };
         $($tail:tt)*
     ) => {
-        $crate::init!(@@init_call($var, $name $(<$($generic),*>)?, $field, field_place, (unsafe {
+        $crate::init!(@@init_call($var, $name [$($generic)*], $field, field_place, (unsafe {
             // SAFETY: macro-caller guarantees this is sound
             $func $(:: $(<$($args),*>::)? $path)*(field_place $($rest)*).await }
         ), $($binding)?));
-        $crate::init!(@@inner($var, $pin, ($($inner)* $field: ::core::panic!(),), ($name $(<$($generic),*>)?)) $($tail)*);
+        $crate::init!(@@inner($var, $pin, ($($inner)* $field: ::core::panic!(),), ($name [$($generic)*])) $($tail)*);
     };
 
     // now again the same patterns with `?`
 
+    // a function call initializing two fields at once with possible error.
+    (@@inner($var:ident, $pin:ident, ($($inner:tt)*), ($name:ident [$($generic:tt)*]))
+        $(~let $binding:pat = )?$func:ident $(:: $(<$($args:ty),*$(,)?>::)? $path:ident)*(.$field1:tt, .$field2:tt $($rest:tt)*)?;
+        $($tail:tt)*
+    ) => {
+        $crate::init!(@@init_call2($var, $name [$($generic)*], ($field1, field_place1), ($field2, field_place2), ($func $(:: $(<$($args),*>::)? $path)*(field_place1, field_place2 $($rest)*)?), $($binding)?));
+        $crate::init!(@@inner($var, $pin, ($($inner)* $field1: ::core::panic!(), $field2: ::core::panic!(),), ($name [$($generic)*])) $($tail)*);
+    };
     // a function call initializing a single field with possible error.
     // we cannot use the `path` meta-variable type, because `(` is not allowed after that :(
-    (@@inner($var:ident, $pin:ident, ($($inner:tt)*), ($name:ident $(<$($generic:ty),*>)?))
-        $(~let $binding:pat = )?$func:ident $(:: $(<$($args:ty),*$(,)?>::)? $path:ident)*(.$field:ident $($rest:tt)*)?;
+    (@@inner($var:ident, $pin:ident, ($($inner:tt)*), ($name:ident [$($generic:tt)*]))
+        $(~let $binding:pat = )?$func:ident $(:: $(<$($args:ty),*$(,)?>::)? $path:ident)*(.$field:tt $($rest:tt)*)?;
         $($tail:tt)*
     ) => {
-        $crate::init!(@@init_call($var, $name $(<$($generic),*>)?, $field, field_place, ($func $(:: $(<$($args),*>::)? $path)*(field_place $($rest)*)?), $($binding)?));
-        $crate::init!(@@inner($var, $pin, ($($inner)* $field: ::core::panic!(),), ($name $(<$($generic),*>)?)) $($tail)*);
+        $crate::init!(@@init_call($var, $name [$($generic)*], $field, field_place, ($func $(:: $(<$($args),*>::)? $path)*(field_place $($rest)*)?), $($binding)?));
+        $crate::init!(@@inner($var, $pin, ($($inner)* $field: ::core::panic!(),), ($name [$($generic)*])) $($tail)*);
     };
     // an unsafe function initializing a single field.
-    (@@inner($var:ident, $pin:ident, ($($inner:tt)*), ($name:ident $(<$($generic:ty),*>)?))
-        $(~let $binding:pat = )?unsafe { $func:ident $(:: $(<$($args:ty),*$(,)?>::)? $path:ident)*(.$field:ident $($rest:tt)*) }?;
+    (@@inner($var:ident, $pin:ident, ($($inner:tt)*), ($name:ident [$($generic:tt)*]))
+        $(~let $binding:pat = )?unsafe { $func:ident $(:: $(<$($args:ty),*$(,)?>::)? $path:ident)*(.$field:tt $($rest:tt)*) }?;
         $($tail:tt)*
     ) => {
-        $crate::init!(@@init_call($var, $name $(<$($generic),*>)?, $field, field_place, (unsafe {
+        $crate::init!(@@init_call($var, $name [$($generic)*], $field, field_place, (unsafe {
             // SAFETY: macro-caller guarantees this is sound
             $func $(:: $(<$($args),*>::)? $path)*(field_place $($rest)*) }?
         ), $($binding)?));
-        $crate::init!(@@inner($var $pin ($($inner)* $field: ::core::panic!(),) $name $(<$($generic),*>)?) $($tail)*);
+        $crate::init!(@@inner($var, $pin, ($($inner)* $field: ::core::panic!(),), ($name [$($generic)*])) $($tail)*);
     };
     // a macro call initializing a single field
-    (@@inner($var:ident, $pin:ident, ($($inner:tt)*), ($name:ident $(<$($generic:ty),*>)?))
-        $(~let $binding:pat = )?$func:ident $(:: $(<$($args:ty),*$(,)?>::)? $path:ident)*!(.$field:ident $($rest:tt)*)?;
+    (@@inner($var:ident, $pin:ident, ($($inner:tt)*), ($name:ident [$($generic:tt)*]))
+        $(~let $binding:pat = )?$func:ident $(:: $(<$($args:ty),*$(,)?>::)? $path:ident)*!(.$field:tt $($rest:tt)*)?;
         $($tail:tt)*
     ) => {
-        $crate::init!(@@init_call($var, $name $(<$($generic),*>)?, $field, field_place, ($func $(:: $(<$($args),*>::)? $path)*!(field_place $($rest)*)?), $($binding)?));
-        $crate::init!(@@inner($var, $pin, ($($inner)* $field: ::core::panic!(),), ($name $(<$($generic),*>)?)) $($tail)*);
+        $crate::init!(@@init_call($var, $name [$($generic)*], $field, field_place, ($func $(:: $(<$($args),*>::)? $path)*!(field_place $($rest)*)?), $($binding)?));
+        $crate::init!(@@inner($var, $pin, ($($inner)* $field: ::core::panic!(),), ($name [$($generic)*])) $($tail)*);
     };
     // an async function call initializing a single field
-    (@@inner($var:ident, $pin:ident, ($($inner:tt)*), ($name:ident $(<$($generic:ty),*>)?))
-        $(~let $binding:pat = )?$func:ident $(:: $(<$($args:ty),*$(,)?>::)? $path:ident)*(.$field:ident $($rest:tt)*).await?;
+    (@@inner($var:ident, $pin:ident, ($($inner:tt)*), ($name:ident [$($generic:tt)*]))
+        $(~let $binding:pat = )?$func:ident $(:: $(<$($args:ty),*$(,)?>::)? $path:ident)*(.$field:tt $($rest:tt)*).await?;
         $($tail:tt)*
     ) => {
-        $crate::init!(@@init_call($var, $name $(<$($generic),*>)?, $field, field_place, ($func $(:: $(<$($args),*>::)? $path)*(field_place $($rest)*).await?), $($binding)?));
-        $crate::init!(@@inner($var, $pin, ($($inner)* $field: ::core::panic!(),), ($name $(<$($generic),*>)?)) $($tail)*);
+        $crate::init!(@@init_call($var, $name [$($generic)*], $field, field_place, ($func $(:: $(<$($args),*>::)? $path)*(field_place $($rest)*).await?), $($binding)?));
+        $crate::init!(@@inner($var, $pin, ($($inner)* $field: ::core::panic!(),), ($name [$($generic)*])) $($tail)*);
     };
     // an unsafe async function call initializing a single field
-    (@@inner($var:ident, $pin:ident, ($($inner:tt)*), ($name:ident $(<$($generic:ty),*>)?))
-        $(~let $binding:pat = )? unsafe { $func:ident $(:: $(<$($args:ty),*$(,)?>::)? $path:ident)*(.$field:ident $($rest:tt)*).await }?;
+    (@@inner($var:ident, $pin:ident, ($($inner:tt)*), ($name:ident [$($generic:tt)*]))
+        $(~let $binding:pat = )? unsafe { $func:ident $(:: $(<$($args:ty),*$(,)?>::)? $path:ident)*(.$field:tt $($rest:tt)*).await }?;
         $($tail:tt)*
     ) => {
-        $crate::init!(@@init_call($var, $name $(<$($generic),*>)?, $field, field_place, (unsafe {
+        $crate::init!(@@init_call($var, $name [$($generic)*], $field, field_place, (unsafe {
             // SAFETY: macro-caller guarantees this is sound
             $func $(:: $(<$($args),*>::)? $path)*(field_place $($rest)*).await }?
         ), $($binding)?));
-        $crate::init!(@@inner($var, $pin, ($($inner)* $field: ::core::panic!(),), ($name $(<$($generic),*>)?)) $($tail)*);
+        $crate::init!(@@inner($var, $pin, ($($inner)* $field: ::core::panic!(),), ($name [$($generic)*])) $($tail)*);
     };
     // a normal statement that will be executed as-is.
     (@@inner($var:ident, $pin:ident, ($($inner:tt)*), ($($name:tt)*))
@@ -307,7 +368,7 @@ macro_rules! init {
         $crate::init!(@@inner($var, $pin, ($($inner)*), ($($name)*)) $($tail)*);
     };
     // generalized function/macro call helper (manual)
-    (@@init_call($var:ident, $name:ident $(<$($generic:ty),*>)?, $field:ident, $field_place:ident, ($($call:tt)*), $($binding:pat)?)) => {
+    (@@init_call($var:ident, $name:ident [$($generic:tt)*], $field:tt, $field_place:ident, ($($call:tt)*), $($binding:pat)?)) => {
         let _result;
         {
             // this type is used as the guard parameter on `(Pin)InitMe` and ensures that we
@@ -327,11 +388,11 @@ macro_rules! init {
             // get the correct pin projection (handled by the ___PinData type)
             let $field_place = unsafe {
                 // SAFETY: calling macro-only functions
-                <$name $(<$($generic),*>)? as $crate::place::___PinData>::___PinData::$field(
-                    ::core::ptr::addr_of_mut!((*$crate::place::PartialInitPlace::___as_mut_ptr(&mut $var, &|_: &$name $(<$($generic),*>)?| {})).$field),
+                $crate::init!(@@pin_data_call(($name<$($generic)*>), $field, (
+                    ::core::ptr::addr_of_mut!((*$crate::place::PartialInitPlace::___as_mut_ptr(&mut $var, &|_: &$name<$($generic)*>| {})).$field),
                     var,
                     ___LocalGuard,
-                )
+                )))
             };
             // create a guard that will be used later, as we want to shadow the type definition to
             // prevent misuse by a proc macro
@@ -348,6 +409,101 @@ macro_rules! init {
         }
         $(let $binding = _result;)?
     };
+    // function call helper for init-functions initializing two fields, works like `@@init_call`
+    // with a guard for each field. The guard of the returned `InitProof` is the tuple of both.
+    // Using the same field twice is rejected by the struct initializer in `@@inner`.
+    (@@init_call2($var:ident, $name:ident [$($generic:tt)*], ($field1:tt, $field_place1:ident), ($field2:tt, $field_place2:ident), ($($call:tt)*), $($binding:pat)?)) => {
+        let _result;
+        {
+            #[doc(hidden)]
+            struct ___LocalGuard1;
+            unsafe impl $crate::Guard for ___LocalGuard1 {}
+            #[doc(hidden)]
+            struct ___LocalGuard2;
+            unsafe impl $crate::Guard for ___LocalGuard2 {}
+            // we need the type of var, but we do not actually want to use it here.
+            let mut var;
+            #[allow(unused_assignments)]
+            {
+                var = Some(&$var);
+            }
+            // overwrite the value so we can borrow $var mutably again
+            var = None;
+            // both field pointers are derived from the same pointer, so creating the second one
+            // does not invalidate the first one.
+            let ptr = unsafe {
+                // SAFETY: calling macro-only functions
+                $crate::place::PartialInitPlace::___as_mut_ptr(&mut $var, &|_: &$name<$($generic)*>| {})
+            };
+            let $field_place1 = unsafe {
+                // SAFETY: calling macro-only functions
+                $crate::init!(@@pin_data_call(($name<$($generic)*>), $field1, (
+                    ::core::ptr::addr_of_mut!((*ptr).$field1),
+                    var,
+                    ___LocalGuard1,
+                )))
+            };
+            let $field_place2 = unsafe {
+                // SAFETY: calling macro-only functions
+                $crate::init!(@@pin_data_call(($name<$($generic)*>), $field2, (
+                    ::core::ptr::addr_of_mut!((*ptr).$field2),
+                    var,
+                    ___LocalGuard2,
+                )))
+            };
+            let guard = (___LocalGuard1, ___LocalGuard2);
+            {
+                // shadow the type defs
+                #[doc(hidden)]
+                struct ___LocalGuard1;
+                #[doc(hidden)]
+                struct ___LocalGuard2;
+                _result = $crate::InitProof::___unwrap($($call)*, guard);
+            }
+        }
+        $(let $binding = _result;)?
+    };
+    // call the pin projection function of `$field` (handled by the ___PinData type). Fields of
+    // tuple structs are named `_0`, `_1`, ... by `pin_data!`.
+    (@@pin_data_call(($($name:tt)*), $field:ident, ($($args:tt)*))) => {
+        <$($name)* as $crate::place::___PinData>::___PinData::$field($($args)*)
+    };
+    (@@pin_data_call(($($name:tt)*), 0, ($($args:tt)*))) => {
+        <$($name)* as $crate::place::___PinData>::___PinData::_0($($args)*)
+    };
+    (@@pin_data_call(($($name:tt)*), 1, ($($args:tt)*))) => {
+        <$($name)* as $crate::place::___PinData>::___PinData::_1($($args)*)
+    };
+    (@@pin_data_call(($($name:tt)*), 2, ($($args:tt)*))) => {
+        <$($name)* as $crate::place::___PinData>::___PinData::_2($($args)*)
+    };
+    (@@pin_data_call(($($name:tt)*), 3, ($($args:tt)*))) => {
+        <$($name)* as $crate::place::___PinData>::___PinData::_3($($args)*)
+    };
+    (@@pin_data_call(($($name:tt)*), 4, ($($args:tt)*))) => {
+        <$($name)* as $crate::place::___PinData>::___PinData::_4($($args)*)
+    };
+    (@@pin_data_call(($($name:tt)*), 5, ($($args:tt)*))) => {
+        <$($name)* as $crate::place::___PinData>::___PinData::_5($($args)*)
+    };
+    (@@pin_data_call(($($name:tt)*), 6, ($($args:tt)*))) => {
+        <$($name)* as $crate::place::___PinData>::___PinData::_6($($args)*)
+    };
+    (@@pin_data_call(($($name:tt)*), 7, ($($args:tt)*))) => {
+        <$($name)* as $crate::place::___PinData>::___PinData::_7($($args)*)
+    };
+    (@@pin_data_call(($($name:tt)*), 8, ($($args:tt)*))) => {
+        <$($name)* as $crate::place::___PinData>::___PinData::_8($($args)*)
+    };
+    (@@pin_data_call(($($name:tt)*), 9, ($($args:tt)*))) => {
+        <$($name)* as $crate::place::___PinData>::___PinData::_9($($args)*)
+    };
+    (@@pin_data_call(($($name:tt)*), 10, ($($args:tt)*))) => {
+        <$($name)* as $crate::place::___PinData>::___PinData::_10($($args)*)
+    };
+    (@@pin_data_call(($($name:tt)*), 11, ($($args:tt)*))) => {
+        <$($name)* as $crate::place::___PinData>::___PinData::_11($($args)*)
+    };
     // generalized single function/macro init helper
     (@@fully_init($var:expr, ($($init:tt)*)$(, $($rest:tt)*)?)) => {
         match $var {
@@ -418,6 +574,69 @@ macro_rules! init {
     };
 }
 
+/// Allocate and initialize a slice with a length only known at runtime.
+///
+/// Works like the `@` forms of [`init!`] that use a single function or macro, but takes the
+/// [`LenAllocablePlace`] to allocate and the length of the slice as the first two arguments. The
+/// function receives an [`InitMe`]`<'_, [T], G>` for the whole slice.
+///
+/// ```rust
+/// use simple_safe_init::*;
+/// use std::sync::Arc;
+///
+/// fn init_range<G: Guard>(slice: InitMe<'_, [u32], G>, start: u32) -> InitProof<(), G> {
+///     slice.write_each(|i| start + i as u32)
+/// }
+///
+/// let len = 3;
+/// let boxed: Box<[u32]> = init_with_len!(@init_range(Box<[u32]>, len, 10)).unwrap();
+/// assert_eq!(*boxed, [10, 11, 12]);
+/// let arc: Arc<[u32]> = init_with_len!(@init_range(Arc<[u32]>, len, 20)).unwrap();
+/// assert_eq!(*arc, [20, 21, 22]);
+/// ```
+///
+/// [`LenAllocablePlace`]: crate::place::LenAllocablePlace
+/// [`InitMe`]: crate::InitMe
+#[macro_export]
+macro_rules! init_with_len {
+    // initialize a specific LenAllocablePlace using a single macro.
+    (@$func:ident $(:: $(<$($args:ty),*$(,)?>::)? $path:ident)*!($var:ty, $len:expr $(, $($rest:tt)*)?)) => {
+        match <$var as $crate::place::LenAllocablePlace>::allocate($len) {
+            Ok(mut var) => {
+                Ok($crate::init!(@@fully_init(var, ($func $(:: $(<$($args),*>::)? $path)*!) $(, $($rest)*)?)))
+            },
+            Err(e) => Err(e),
+        }.map(<$var as $crate::place::LenAllocablePlace>::after_init)
+    };
+    // initialize a specific LenAllocablePlace using a single macro with error propagation
+    (@$func:ident $(:: $(<$($args:ty),*$(,)?>::)? $path:ident)*!($var:ty, $len:expr $(, $($rest:tt)*)?)?) => {
+        match <$var as $crate::place::LenAllocablePlace>::allocate($len) {
+            Ok(mut var) => {
+                $crate::init!(@@fully_init(var, err, ($func $(:: $(<$($args),*>::)? $path)*!) $(, $($rest)*)?))
+            },
+            Err(e) => Err(e),
+        }.map(<$var as $crate::place::LenAllocablePlace>::after_init)
+    };
+    // initialize a specific LenAllocablePlace using a single function.
+    (@$func:ident $(:: $(<$($args:ty),*$(,)?>::)? $path:ident)*($var:ty, $len:expr $(, $($rest:tt)*)?)) => {
+        match <$var as $crate::place::LenAllocablePlace>::allocate($len) {
+            Ok(mut var) => {
+                Ok($crate::init!(@@fully_init(var, ($func $(:: $(<$($args),*>::)? $path)*) $(, $($rest)*)?)))
+            },
+            Err(e) => Err(e),
+        }.map(<$var as $crate::place::LenAllocablePlace>::after_init)
+    };
+    // initialize a specific LenAllocablePlace using a single function with error propagation
+    (@$func:ident $(:: $(<$($args:ty),*$(,)?>::)? $path:ident)*($var:ty, $len:expr $(, $($rest:tt)*)?)?) => {
+        match <$var as $crate::place::LenAllocablePlace>::allocate($len) {
+            Ok(mut var) => {
+                $crate::init!(@@fully_init(var, err, ($func $(:: $(<$($args),*>::)? $path)*) $(, $($rest)*)?))
+            },
+            Err(e) => Err(e),
+        }.map(<$var as $crate::place::LenAllocablePlace>::after_init)
+    };
+}
+
 /// Used to specify the pin information of the fields of a struct.
 ///
 /// This is somewhat similar in purpose as
@@ -425,12 +644,126 @@ macro_rules! init {
 /// Place this macro around a struct definition and then `#pin` in front of the attributes of each
 /// field you want to have structurally pinned.
 ///
+/// Tuple structs (with up to 12 fields) are also supported, their fields are initialized using
+/// `.0`, `.1`, ... in [`init!`]:
+/// ```rust
+/// use core::{marker::PhantomPinned, mem::MaybeUninit};
+/// use simple_safe_init::*;
+///
+/// pin_data! {
+///     #[derive(Debug)]
+///     struct Wrapper<T>(#pin T, PhantomPinned);
+/// }
+///
+/// let wrapper = Box::pin(MaybeUninit::uninit());
+/// let wrapper = init! { wrapper => Wrapper<u64> {
+///     PinInitMe::write(.0, 42);
+///     .1 = PhantomPinned;
+/// }};
+/// println!("{wrapper:?}");
+/// ```
+///
+/// Lifetimes and `where` clauses are copied to the generated code:
+/// ```rust
+/// use core::{fmt::Debug, mem::MaybeUninit};
+/// use simple_safe_init::*;
+///
+/// pin_data! {
+///     struct Foo<'a, T> where T: Debug + 'a {
+///         #pin
+///         inner: &'a T,
+///     }
+/// }
+///
+/// let value = 42;
+/// let foo = Box::pin(MaybeUninit::uninit());
+/// let foo = init! { foo => Foo<i32> {
+///     PinInitMe::write(.inner, &value);
+/// }};
+/// println!("{:?}", foo.inner);
+/// ```
+/// Lifetime arguments can be omitted in [`init!`] (as above) or be given explicitly, e.g.
+/// `Foo<'_, i32>`.
+///
+/// Const parameters (`const N: usize`) are supported as well. When naming the type in [`init!`],
+/// their arguments need to be literals or blocks (`RingBuf<u8, { N }>`).
+///
+/// [`PhantomData`] and [`PhantomPinned`] fields still need to be initialized in [`init!`] (like
+/// `.1` above), because [`init!`] checks that every field is initialized exactly once. The
+/// init-functions generated by `#[derive(Init)]` and `#[derive(PinInit)]` initialize them
+/// automatically.
+///
+/// [`init!`]: crate::init!
+/// [`PhantomData`]: core::marker::PhantomData
+/// [`PhantomPinned`]: core::marker::PhantomPinned
+///
 /// TODO: fuse with it or re-implement it?
 #[macro_export]
 macro_rules! pin_data {
     (
         $(#[$struct_attr:meta])*
-        $vis:vis struct $name:ident $(<$($($life:lifetime),+ $(,)?)? $($generic:ident $(: [$($bounds:tt)*])?),* $(,)?>)? $(where $($whr:path : $bound:ty),* $(,)?)? {
+        $vis:vis struct $name:ident <
+            $($($life:lifetime $(: $lbound:lifetime $(+ $lbounds:lifetime)*)?),+ $(,)?)?
+            $($param:ident $($cname:ident)? $(: [$($bounds:tt)*])? $(: $cty:ident)?),* $(,)?
+        >
+        $($rest:tt)*
+    ) => {
+        $crate::pin_data!(@@generics(
+            ($(#[$struct_attr])* $vis struct $name),
+            [$($($life $(: $lbound $(+ $lbounds)*)?,)+)? $($param $($cname)? $(: $($bounds)*)? $(: $cty)?,)*],
+            [$($($life,)+)?],
+            ($(($param $($cname)?))*),
+            $($rest)*
+        ));
+    };
+    (
+        $(#[$struct_attr:meta])*
+        $vis:vis struct $name:ident $($rest:tt)*
+    ) => {
+        $crate::pin_data!(@@body(($(#[$struct_attr])* $vis struct $name), ([] []), $($rest)*));
+    };
+    // the generic arguments used to name the struct, const parameters need to be stripped of the
+    // `const` keyword.
+    (@@generics($head:tt, $decls:tt, [$($args:tt)*], ((const $cname:ident) $($params:tt)*), $($rest:tt)*)) => {
+        $crate::pin_data!(@@generics($head, $decls, [$($args)* $cname,], ($($params)*), $($rest)*));
+    };
+    (@@generics($head:tt, $decls:tt, [$($args:tt)*], (($param:ident) $($params:tt)*), $($rest:tt)*)) => {
+        $crate::pin_data!(@@generics($head, $decls, [$($args)* $param,], ($($params)*), $($rest)*));
+    };
+    (@@generics($head:tt, $decls:tt, $args:tt, (), $($rest:tt)*)) => {
+        $crate::pin_data!(@@body($head, ($decls $args), $($rest)*));
+    };
+    (@@body($head:tt, $generics:tt, { $($fields:tt)* })) => {
+        $crate::pin_data!(@@struct($head, $generics, [], { $($fields)* }));
+    };
+    (@@body($head:tt, $generics:tt, where $($rest:tt)*)) => {
+        $crate::pin_data!(@@where($head, $generics, [], $($rest)*));
+    };
+    (@@body($head:tt, $generics:tt, ( $($fields:tt)* ) $($rest:tt)*)) => {
+        $crate::pin_data!(@@tuple_where($head, $generics, ($($fields)*), [], $($rest)*));
+    };
+    // the where clause cannot be parsed using fragments, so we collect its tokens until only the
+    // body of the struct (or the `;` for tuple structs) remains.
+    (@@where($head:tt, $generics:tt, [$($whr:tt)*], { $($fields:tt)* })) => {
+        $crate::pin_data!(@@struct($head, $generics, [$($whr)*], { $($fields)* }));
+    };
+    (@@where($head:tt, $generics:tt, [$($whr:tt)*], $next:tt $($rest:tt)*)) => {
+        $crate::pin_data!(@@where($head, $generics, [$($whr)* $next], $($rest)*));
+    };
+    (@@tuple_where($head:tt, $generics:tt, $fields:tt, [], where $($rest:tt)*)) => {
+        $crate::pin_data!(@@tuple_where($head, $generics, $fields, [], $($rest)*));
+    };
+    (@@tuple_where($head:tt, $generics:tt, $fields:tt, [$($whr:tt)*], ;)) => {
+        $crate::pin_data!(@@tuple_struct($head, $generics, [$($whr)*], $fields));
+    };
+    (@@tuple_where($head:tt, $generics:tt, $fields:tt, [$($whr:tt)*], $next:tt $($rest:tt)*)) => {
+        $crate::pin_data!(@@tuple_where($head, $generics, $fields, [$($whr)* $next], $($rest)*));
+    };
+    (@@struct(
+        ($(#[$struct_attr:meta])* $vis:vis struct $name:ident),
+        ([$($decls:tt)*] [$($args:tt)*]),
+        [$($whr:tt)*],
+        {
             $(
                 $(#$pin:ident)?
                 $(#[$attr:meta])*
@@ -438,9 +771,9 @@ macro_rules! pin_data {
             ),*
             $(,)?
         }
-    ) => {
+    )) => {
         $(#[$struct_attr])*
-        $vis struct $name $(<$($($life),+ ,)? $($generic $(: $($bounds)*)?),*>)? $(where $($whr : $bound),*)? {
+        $vis struct $name<$($decls)*> where $($whr)* {
             $(
                 $(#[$attr])*
                 $fvis $field: $typ
@@ -449,6 +782,7 @@ macro_rules! pin_data {
 
         const _: () = {
             #[doc(hidden)]
+            #[derive(Debug)]
             $vis struct ___ThePinData;
 
             impl ___ThePinData {
@@ -457,11 +791,55 @@ macro_rules! pin_data {
                 )*
             }
 
-            unsafe impl$(<$($($life),+ ,)? $($generic $(: $($bounds)*)?),*>)? $crate::place::___PinData for $name$(<$($($life),+ ,)? $($generic),*>)? {
+            unsafe impl<$($decls)*> $crate::place::___PinData for $name<$($args)*> where $($whr)* {
                 type ___PinData = ___ThePinData;
             }
         };
     };
+    (@@tuple_struct(
+        ($(#[$struct_attr:meta])* $vis:vis struct $name:ident),
+        ([$($decls:tt)*] [$($args:tt)*]),
+        [$($whr:tt)*],
+        (
+            $(
+                $(#$pin:ident)?
+                $(#[$attr:meta])*
+                $fvis:vis $typ:ty
+            ),*
+            $(,)?
+        )
+    )) => {
+        $(#[$struct_attr])*
+        $vis struct $name<$($decls)*> (
+            $(
+                $(#[$attr])*
+                $fvis $typ
+            ),*
+        ) where $($whr)*;
+
+        const _: () = {
+            #[doc(hidden)]
+            #[derive(Debug)]
+            $vis struct ___ThePinData;
+
+            impl ___ThePinData {
+                $crate::pin_data!(@@make_tuple_fns(
+                    (_0 _1 _2 _3 _4 _5 _6 _7 _8 _9 _10 _11),
+                    $((($fvis) [$($pin)?] $typ))*
+                ));
+            }
+
+            unsafe impl<$($decls)*> $crate::place::___PinData for $name<$($args)*> where $($whr)* {
+                type ___PinData = ___ThePinData;
+            }
+        };
+    };
+    // tuple struct fields do not have names, so we take the next one from the list.
+    (@@make_tuple_fns(($next:ident $($names:ident)*), (($vis:vis) [$($pin:ident)?] $typ:ty) $($rest:tt)*)) => {
+        $crate::pin_data!(@@make_fn(($vis) $($pin)? $next: $typ));
+        $crate::pin_data!(@@make_tuple_fns(($($names)*), $($rest)*));
+    };
+    (@@make_tuple_fns(($($names:ident)*), )) => {};
     (@@make_fn(($vis:vis) pin $field:ident : $typ:ty)) => {
         $vis unsafe fn $field<'a, T, P: $crate::place::PinnedPlace, G: $crate::Guard>(ptr: *mut T, _place: Option<&P>, guard: G) -> $crate::PinInitMe<'a, T, G> {
             unsafe {
@@ -547,10 +925,15 @@ macro_rules! pin_data {
 /// my_struct.as_mut().print_info();
 /// ```
 ///
+/// With the `stack_limit` feature, using a type larger than [`MAX_STACK_INIT_SIZE`] is a compile
+/// error. The limit can be raised with the `SIMPLE_SAFE_INIT_MAX_STACK_INIT_SIZE` environment
+/// variable.
+///
+/// [`MAX_STACK_INIT_SIZE`]: crate::MAX_STACK_INIT_SIZE
 #[macro_export]
 macro_rules! stack_init {
-    ($var:ident: $typ:ident $(<$($generic:ty),*>)? => { $($tail:tt)* }) => {
-        let mut $var: $crate::place::___StackInit<$typ $(<$($generic),*>)?> = unsafe {
+    ($var:ident: $typ:ident $(<$($($lit:literal)? $({$($block:tt)*})? $($generic:ty)?),*>)? => { $($tail:tt)* }) => {
+        let mut $var: $crate::place::___StackInit<$typ $(<$($($lit)? $({$($block)*})? $($generic)?),*>)?> = unsafe {
             // SAFETY: macro only func
             $crate::place::___StackInit::___new()
         };
@@ -564,7 +947,7 @@ macro_rules! stack_init {
             let $var = unsafe {
                 // SAFETY: we never move out of $var and shadow it at the end so
                 // no one can move out of it.
-                <$crate::PinInitMe<'_, $typ $(<$($generic),*>)?, ___LocalGuard> as $crate::InitPointer<'_, $typ $(<$($generic),*>)?, ___LocalGuard>>::___new(
+                <$crate::PinInitMe<'_, $typ $(<$($($lit)? $({$($block)*})? $($generic)?),*>)?, ___LocalGuard> as $crate::InitPointer<'_, $typ $(<$($($lit)? $({$($block)*})? $($generic)?),*>)?, ___LocalGuard>>::___new(
                     $crate::place::___StackInit::___as_mut_ptr(&mut $var),
                     ___LocalGuard
                 )
@@ -583,7 +966,7 @@ macro_rules! stack_init {
                 struct ___LocalGuard;
                 let () = $crate::InitProof::___unwrap(
                     // because we requrie a normal InitProof here, the initialization cannot error
-                    $crate::init! { $var => $typ $(<$($generic),*>)? { $($tail)* }},
+                    $crate::init! { $var => $typ $(<$($($lit)? $({$($block)*})? $($generic)?),*>)? { $($tail)* }},
                     guard
                 );
                 ::core::mem::forget(g);
@@ -594,8 +977,8 @@ macro_rules! stack_init {
             ::core::pin::Pin::new_unchecked($crate::place::___StackInit::___assume_init_mut(&mut $var))
         };
     };
-    ($var:ident: $typ:ident $(<$($generic:ty),*>)? => ( $($tail:tt)* )) => {
-        let mut $var: $crate::place::___StackInit<$typ $(<$($generic),*>)?> = unsafe {
+    ($var:ident: $typ:ident $(<$($($lit:literal)? $({$($block:tt)*})? $($generic:ty)?),*>)? => ( $($tail:tt)* )) => {
+        let mut $var: $crate::place::___StackInit<$typ $(<$($($lit)? $({$($block)*})? $($generic)?),*>)?> = unsafe {
             // SAFETY: macro only func
             $crate::place::___StackInit::___new()
         };
@@ -609,7 +992,7 @@ macro_rules! stack_init {
             let $var = unsafe {
                 // SAFETY: we never move out of $var and shadow it at the end so
                 // no one can move out of it.
-                <$crate::PinInitMe<'_, $typ $(<$($generic),*>)?, ___LocalGuard> as $crate::InitPointer<'_, $typ $(<$($generic),*>)?, ___LocalGuard>>::___new(
+                <$crate::PinInitMe<'_, $typ $(<$($($lit)? $({$($block)*})? $($generic)?),*>)?, ___LocalGuard> as $crate::InitPointer<'_, $typ $(<$($($lit)? $({$($block)*})? $($generic)?),*>)?, ___LocalGuard>>::___new(
                     $crate::place::___StackInit::___as_mut_ptr(&mut $var),
                     ___LocalGuard
                 )
@@ -641,30 +1024,80 @@ macro_rules! stack_init {
     };
 }
 
-/// Declare and initialize a static variable using a user-defined ctor implementation.
+/// Declare and initialize a static variable using a ctor.
+///
+/// On Linux, Android, the BSDs, Apple platforms and Windows, the linker can run the initializer
+/// before `main` without any additional crates:
+/// ```rust
+/// use simple_safe_init::*;
 ///
-/// You will need to specify a ctor macro that takes a single function
-/// (`unsafe extern "C" fn()`) as its only parameter. It will need to execute that function in the
-/// ctor.
+/// pin_data! {
+///     pub struct Config {
+///         name: &'static str,
+///         verbose: bool,
+///     }
+/// }
+///
+/// static_init! {
+///     [unsafe]
+///     static CONFIG: Config = |this| {
+///         this => Config {
+///             .name = "simple-safe-init";
+///             .verbose = false;
+///         }
+///     };
+/// }
+///
+/// fn main() {
+///     assert_eq!(CONFIG.name, "simple-safe-init");
+/// }
+/// ```
+///
+/// On other platforms (or to use a different mechanism), you will need to specify a ctor macro
+/// that takes a single function (`unsafe extern "C" fn()`) as its only parameter. It will need to
+/// execute that function in the ctor:
+/// ```rust,ignore
+/// static_init! {
+///     [unsafe { my_ctor }]
+///     static CONFIG: Config = |this| { init_config(this) };
+/// }
+/// ```
+///
+/// The initializer of each static is the input of [`init!`], the parameter is bound to the
+/// [`PinInitMe`] of the static.
 ///
 /// # Safety
-/// You need to supply a valid ctor macro, it needs to satisfy the following properties:
+///
+/// No initializer is allowed to access a static declared with this macro, as the order in which
+/// ctors are run is not specified.
+///
+/// When supplying a ctor macro, it needs to satisfy the following properties:
 /// - take a single parameter that is a path to a function with no parameters and C calling
 /// convention,
 /// - that function needs to be called within a static initializer, such that no accesses occur to the
 /// statics defined here.
 ///
-///
+/// [`init!`]: crate::init!
+/// [`PinInitMe`]: crate::PinInitMe
 #[macro_export]
 macro_rules! static_init {
+    (
+        [unsafe]
+        $($statics:tt)*
+    ) => {
+        $crate::static_init! {
+            [unsafe { $crate::___ctor }]
+            $($statics)*
+        }
+    };
     (
         [unsafe { $invoke_ctor:path }]
         $(
-            $(#[$attr:meta])* $v:vis static $name:ident: $typ:ty $(= {$($inner:tt)*})?;
+            $(#[$attr:meta])* $v:vis static $name:ident: $typ:ty = |$this:ident| {$($inner:tt)*};
         )*
     ) => {
         $(
-            $(#[$attr])* $v static $name: $crate::place::StaticInit<$typ> = {
+            $(#[$attr])* $v static $name: $crate::place::___StaticInit<$typ> = {
                 #[doc(hidden)]
                 unsafe extern "C" fn construct() {
                     // SAFETY: the constructor is only accessible to the invoke_ctor
@@ -674,8 +1107,8 @@ macro_rules! static_init {
                     let guard = ___LocalGuard;
                     // SAFETY: static cannot move, so we can init it in place.
                     let pinned = unsafe {
-                        $crate::PinInitMe::___new(
-                            $crate::place::StaticInit::___as_mut_ptr(&$name),
+                        <$crate::PinInitMe<'_, $typ, ___LocalGuard> as $crate::InitPointer<'_, $typ, ___LocalGuard>>::___new(
+                            $crate::place::___StaticInit::___as_mut_ptr(&$name),
                             guard
                         )
                     };
@@ -683,21 +1116,54 @@ macro_rules! static_init {
                     {
                         #[doc(hidden)]
                         struct ___LocalGuard;
-                        let $name = pinned;
-                        let () = $crate::InitProof::___unwrap(init!($($inner)*), guard);
+                        let $this = pinned;
+                        let () = $crate::InitProof::___unwrap($crate::init!($($inner)*), guard);
                     }
                 }
-                unsafe {
-                    // SAFETY: caller used an unsafe block to specify the `invoke_ctor!` macro.
-                    // so we can use it here.
-                    invoke_ctor!(construct);
-                }
+                $invoke_ctor!(construct);
                 unsafe {
                     // SAFETY: we require the macro caller to provide a valid `invoke_ctor!`
                     // which we called with our initializer above.
-                    $crate::place::StaticInit<$typ>::___new()
+                    <$crate::place::___StaticInit<$typ>>::___new()
                 }
             };
         )*
     };
 }
+
+/// Registers `$ctor` to be run before `main` using the platform's init section.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! ___ctor {
+    ($ctor:path) => {
+        #[cfg(not(any(
+            target_os = "linux",
+            target_os = "android",
+            target_os = "freebsd",
+            target_os = "netbsd",
+            target_os = "openbsd",
+            target_os = "dragonfly",
+            target_vendor = "apple",
+            windows,
+        )))]
+        ::core::compile_error!(
+            "`static_init!` has no built-in ctor for this target, supply one with `[unsafe { my_ctor }]`"
+        );
+
+        #[used]
+        #[cfg_attr(
+            any(
+                target_os = "linux",
+                target_os = "android",
+                target_os = "freebsd",
+                target_os = "netbsd",
+                target_os = "openbsd",
+                target_os = "dragonfly",
+            ),
+            link_section = ".init_array"
+        )]
+        #[cfg_attr(target_vendor = "apple", link_section = "__DATA,__mod_init_func")]
+        #[cfg_attr(windows, link_section = ".CRT$XCU")]
+        static ___CTOR: unsafe extern "C" fn() = $ctor;
+    };
+}
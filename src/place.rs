@@ -8,13 +8,13 @@
 use super::{Guard, InitMe, InitPointer, PinInitMe};
 #[cfg(feature = "alloc")]
 use alloc::boxed::Box;
-use core::{cell::UnsafeCell, mem::MaybeUninit, pin::Pin};
+use core::{cell::UnsafeCell, convert::Infallible, mem::MaybeUninit, pin::Pin};
 
 macro_rules! cfg_std {
     ($($stuff:item)*) => {
         $(
             // TODO change to docsrs
-            #[cfg_attr(feature = "docsrs", doc(cfg(feature = "alloc")))]
+            #[cfg_attr(all(feature = "docsrs", nightly), doc(cfg(feature = "alloc")))]
             #[cfg(feature = "alloc")]
             $stuff
         )*
@@ -47,6 +47,59 @@ macro_rules! cfg_std {
 /// access the memory, use that to initialize it and then convert it to the actual smart pointer.
 /// For this use-case, view the source code of [`UniqueArc<T>`] and [`UniqueRc<T>`].
 ///
+/// To support pinned initialization through [`Pin<Self>`], you also need to implement
+/// [`OwnedPlace`].
+///
+/// Raw pointers such as [`NonNull<MaybeUninit<T>>`] do not implement this trait, since they can
+/// be created in safe code without pointing to valid memory (e.g. [`NonNull::dangling`]). Memory
+/// from a custom allocator should be wrapped in a type that can only be created with valid memory:
+/// ```rust
+/// use core::{alloc::Layout, mem::MaybeUninit, ptr::NonNull};
+/// use simple_safe_init::{place::PartialInitPlace, *};
+///
+/// struct RawBox<T> {
+///     ptr: NonNull<T>,
+/// }
+///
+/// impl<T> RawBox<MaybeUninit<T>> {
+///     /// # Safety
+///     ///
+///     /// `ptr` is valid for reads and writes and is exclusively owned by the returned value.
+///     unsafe fn from_raw(ptr: NonNull<MaybeUninit<T>>) -> Self {
+///         Self { ptr }
+///     }
+/// }
+///
+/// unsafe impl<T> PartialInitPlace for RawBox<MaybeUninit<T>> {
+///     type Init = RawBox<T>;
+///     type Raw = T;
+///     type InitMe<'a, G: Guard> = InitMe<'a, T, G> where Self: 'a;
+///
+///     unsafe fn ___assume_init(this: Self) -> Self::Init {
+///         // `MaybeUninit<T>` and `T` are layout compatible.
+///         RawBox { ptr: this.ptr.cast() }
+///     }
+///
+///     unsafe fn ___as_mut_ptr(this: &mut Self, _proof: &impl FnOnce(&Self::Raw)) -> *mut Self::Raw {
+///         this.ptr.as_ptr().cast()
+///     }
+/// }
+///
+/// struct Foo {
+///     a: u32,
+/// }
+///
+/// let ptr = unsafe { std::alloc::alloc(Layout::new::<Foo>()) };
+/// let raw = unsafe { RawBox::from_raw(NonNull::new(ptr).unwrap().cast()) };
+/// let foo = init! { raw => Foo {
+///     .a = 42;
+/// }};
+/// assert_eq!(unsafe { foo.ptr.as_ref() }.a, 42);
+/// # unsafe { std::alloc::dealloc(ptr, Layout::new::<Foo>()) };
+/// ```
+///
+/// [`NonNull<MaybeUninit<T>>`]: core::ptr::NonNull
+/// [`NonNull::dangling`]: core::ptr::NonNull::dangling
 /// [`UniqueArc<T>`]: super::unique::UniqueArc
 /// [`UniqueRc<T>`]: super::unique::UniqueRc
 pub unsafe trait PartialInitPlace {
@@ -112,6 +165,21 @@ pub unsafe trait PartialInitPlace {
 /// The value at this place cannot be moved.
 pub unsafe trait PinnedPlace: PartialInitPlace {}
 
+/// Marker trait used to mark Places that own their pointee. Only these can be pinned using
+/// [`Pin`].
+///
+/// # Safety
+///
+/// When [`Self::Init`] is dropped, the pointee is either dropped before its memory is
+/// invalidated or repurposed, or its memory is leaked.
+///
+/// For example `&mut MaybeUninit<T>` cannot implement this trait: after initializing, the `T` would
+/// never be dropped, but the memory of the [`MaybeUninit<T>`] can be reused afterwards.
+///
+/// [`Self::Init`]: PartialInitPlace::Init
+pub unsafe trait OwnedPlace: PartialInitPlace {}
+
+#[cfg(nightly)]
 unsafe impl PartialInitPlace for ! {
     type Init = !;
     type Raw = !;
@@ -126,6 +194,23 @@ unsafe impl PartialInitPlace for ! {
     }
 }
 
+unsafe impl PartialInitPlace for Infallible {
+    type Init = Infallible;
+    type Raw = Infallible;
+    type InitMe<'a, G: Guard>
+        = InitMe<'a, Infallible, G>
+    where
+        Self: 'a;
+
+    unsafe fn ___assume_init(this: Self) -> Self::Init {
+        this
+    }
+
+    unsafe fn ___as_mut_ptr(this: &mut Self, _proof: &impl FnOnce(&Self::Raw)) -> *mut Self::Raw {
+        match *this {}
+    }
+}
+
 unsafe impl<T> PartialInitPlace for MaybeUninit<T> {
     type Init = T;
     type Raw = T;
@@ -145,6 +230,25 @@ unsafe impl<T> PartialInitPlace for MaybeUninit<T> {
     }
 }
 
+unsafe impl<'r, T> PartialInitPlace for &'r mut MaybeUninit<T> {
+    type Init = &'r mut T;
+    type Raw = T;
+    type InitMe<'a, G: Guard>
+    = InitMe<'a, T, G>
+    where
+        Self: 'a
+    ;
+
+    unsafe fn ___assume_init(this: Self) -> Self::Init {
+        // SAFETY: `T` has been initialized.
+        unsafe { this.assume_init_mut() }
+    }
+
+    unsafe fn ___as_mut_ptr(this: &mut Self, _proof: &impl FnOnce(&Self::Raw)) -> *mut Self::Raw {
+        this.as_mut_ptr()
+    }
+}
+
 cfg_std! {
     unsafe impl<T> PartialInitPlace for Box<MaybeUninit<T>> {
         type Init = Box<T>;
@@ -164,11 +268,34 @@ cfg_std! {
             MaybeUninit::as_mut_ptr(&mut **this)
         }
     }
+
+    unsafe impl<T> OwnedPlace for Box<MaybeUninit<T>> {}
+
+    unsafe impl<T> PartialInitPlace for Box<[MaybeUninit<T>]> {
+        type Init = Box<[T]>;
+        type Raw = [T];
+        type InitMe<'a, G: Guard>
+        = InitMe<'a, [T], G>
+        where
+            Self: 'a
+        ;
+
+        unsafe fn ___assume_init(this: Self) -> Self::Init {
+            // SAFETY: all elements have been initialized
+            unsafe { this.assume_init() }
+        }
+
+        unsafe fn ___as_mut_ptr(this: &mut Self, _proof: &impl FnOnce(&Self::Raw)) -> *mut Self::Raw {
+            core::ptr::slice_from_raw_parts_mut(this.as_mut_ptr().cast::<T>(), this.len())
+        }
+    }
+
+    unsafe impl<T> OwnedPlace for Box<[MaybeUninit<T>]> {}
 }
 
 unsafe impl<P, T> PartialInitPlace for Pin<P>
 where
-    P: PartialInitPlace + core::ops::DerefMut<Target = T>,
+    P: OwnedPlace + core::ops::DerefMut<Target = T>,
     P::Init: core::ops::Deref<Target = P::Raw>,
     T: PartialInitPlace<Raw = P::Raw>,
 {
@@ -194,7 +321,7 @@ where
 
 unsafe impl<P, T> PinnedPlace for Pin<P>
 where
-    P: PartialInitPlace + core::ops::DerefMut<Target = T>,
+    P: OwnedPlace + core::ops::DerefMut<Target = T>,
     P::Init: core::ops::Deref<Target = P::Raw>,
     T: PartialInitPlace<Raw = P::Raw>,
 {
@@ -221,44 +348,116 @@ pub trait AllocablePlace {
     /// # Errors
     ///
     /// This might fail when not enough memory of the specified kind is available.
-    /// If it cannot fail, `Self::Error` should be `!` (the [never type](https://doc.rust-lang.org/reference/types/never.html)).
+    /// If it cannot fail, `Self::Error` should be `!` (the [never type](https://doc.rust-lang.org/reference/types/never.html)),
+    /// or [`Infallible`] on stable Rust.
     fn allocate() -> Result<Self::Alloced, Self::Error>;
 
     /// After initialization operation.
     fn after_init(alloced: <Self::Alloced as PartialInitPlace>::Init) -> Self::Final;
 }
 
-impl<A: AllocablePlace> AllocablePlace for Pin<A>
-where
-    Pin<A::Alloced>: From<A::Alloced> + PartialInitPlace,
-{
-    type Error = A::Error;
-    type Alloced = Pin<A::Alloced>;
-    type Final = <Self::Alloced as PartialInitPlace>::Init;
+/// Helper trait used to allocate places holding a slice.
+///
+/// Works like [`AllocablePlace`], except that the length of the slice is only known at runtime.
+/// Types marked with this trait can be allocated and initialized in one go using
+/// [`init_with_len!`].
+///
+/// [`init_with_len!`]: crate::init_with_len!
+pub trait LenAllocablePlace {
+    /// Error type that may occur when trying to allocate this type of place.
+    type Error;
+    /// The type of the alloced place
+    type Alloced: PartialInitPlace + Sized;
+    /// The type [`<Self::Alloced as PartialInitPlace>::Init`] gets converted to after
+    /// initialization.
+    ///
+    /// [`<Self::Alloced as PartialInitPlace>::Init`]: PartialInitPlace::Init
+    type Final;
 
-    fn allocate() -> Result<Self::Alloced, A::Error> {
-        Ok(Pin::from(A::allocate()?))
-    }
+    /// Allocate a place of this kind holding `len` elements.
+    ///
+    /// # Errors
+    ///
+    /// This might fail when not enough memory of the specified kind is available.
+    fn allocate(len: usize) -> Result<Self::Alloced, Self::Error>;
 
-    fn after_init(alloced: <Self::Alloced as PartialInitPlace>::Init) -> Self::Final {
-        alloced
-    }
+    /// After initialization operation.
+    fn after_init(alloced: <Self::Alloced as PartialInitPlace>::Init) -> Self::Final;
 }
 
 cfg_std! {
+    /// The error returned when an allocation fails.
+    ///
+    /// On stable Rust, allocation failures abort and this is [`Infallible`].
+    #[cfg(nightly)]
+    pub type AllocError = alloc::alloc::AllocError;
+    /// The error returned when an allocation fails.
+    ///
+    /// On stable Rust, allocation failures abort and this is [`Infallible`].
+    #[cfg(not(nightly))]
+    pub type AllocError = Infallible;
+
     impl<T> AllocablePlace for Box<T> {
-        type Error = alloc::alloc::AllocError;
+        type Error = AllocError;
         type Alloced = Box<MaybeUninit<T>>;
         type Final = Box<T>;
 
         fn allocate() -> Result<Self::Alloced, Self::Error> {
-            Box::try_new_uninit()
+            try_new_uninit()
         }
 
         fn after_init(alloced: <Self::Alloced as PartialInitPlace>::Init) -> Self::Final {
             alloced
         }
     }
+
+    impl<T> AllocablePlace for Pin<Box<T>> {
+        type Error = AllocError;
+        type Alloced = Pin<Box<MaybeUninit<T>>>;
+        type Final = Pin<Box<T>>;
+
+        fn allocate() -> Result<Self::Alloced, Self::Error> {
+            try_new_uninit().map(Pin::from)
+        }
+
+        fn after_init(alloced: <Self::Alloced as PartialInitPlace>::Init) -> Self::Final {
+            alloced
+        }
+    }
+
+    impl<T> LenAllocablePlace for Box<[T]> {
+        type Error = AllocError;
+        type Alloced = Box<[MaybeUninit<T>]>;
+        type Final = Box<[T]>;
+
+        fn allocate(len: usize) -> Result<Self::Alloced, Self::Error> {
+            try_new_uninit_slice(len)
+        }
+
+        fn after_init(alloced: <Self::Alloced as PartialInitPlace>::Init) -> Self::Final {
+            alloced
+        }
+    }
+
+    #[cfg(nightly)]
+    fn try_new_uninit_slice<T>(len: usize) -> Result<Box<[MaybeUninit<T>]>, AllocError> {
+        Box::try_new_uninit_slice(len)
+    }
+
+    #[cfg(not(nightly))]
+    fn try_new_uninit_slice<T>(len: usize) -> Result<Box<[MaybeUninit<T>]>, AllocError> {
+        Ok(Box::new_uninit_slice(len))
+    }
+
+    #[cfg(nightly)]
+    fn try_new_uninit<T>() -> Result<Box<MaybeUninit<T>>, AllocError> {
+        Box::try_new_uninit()
+    }
+
+    #[cfg(not(nightly))]
+    fn try_new_uninit<T>() -> Result<Box<MaybeUninit<T>>, AllocError> {
+        Ok(Box::new_uninit())
+    }
 }
 
 /// # ⛔⛔⛔ **MACRO ONLY STRUCT** ⛔⛔⛔
@@ -297,6 +496,9 @@ impl<T> ___StaticInit<T> {
     }
 }
 
+// SAFETY: the value is only mutated by the ctor, before any other access can occur.
+unsafe impl<T: Sync> Sync for ___StaticInit<T> {}
+
 impl<T> core::ops::Deref for ___StaticInit<T> {
     type Target = T;
 
@@ -336,6 +538,13 @@ impl<T> ___StackInit<T> {
     #[doc = include_str!("macro_only.md")]
     /// - the returned value is initialized before it is used.
     pub const unsafe fn ___new() -> Self {
+        #[cfg(feature = "stack_limit")]
+        const {
+            assert!(
+                core::mem::size_of::<T>() <= crate::MAX_STACK_INIT_SIZE,
+                "Type too large for stack init, consider initializing it in a `Pin<Box<T>>` instead"
+            );
+        }
         Self {
             inner: MaybeUninit::uninit(),
             drop: false,
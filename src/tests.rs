@@ -141,3 +141,875 @@ fn delegate() {}
 /// ```
 ///
 fn bad_delegate() {}
+
+/// ```rust
+/// use core::{marker::PhantomPinned, mem::MaybeUninit};
+/// use simple_safe_init::*;
+///
+/// pin_data! {
+///     #[derive(Debug)]
+///     struct Wrapper<T>(#pin T, u32, PhantomPinned);
+/// }
+///
+/// fn init_u64<G: Guard>(this: PinInitMe<'_, u64, G>) -> InitProof<(), G> {
+///     this.write(42)
+/// }
+///
+/// fn init_u32<G: Guard>(this: InitMe<'_, u32, G>) -> InitProof<(), G> {
+///     this.write(7)
+/// }
+///
+/// let wrapper = Box::pin(MaybeUninit::uninit());
+/// let wrapper = init! { wrapper => Wrapper<u64> {
+///     init_u64(.0);
+///     init_u32(.1);
+///     .2 = PhantomPinned;
+/// }};
+/// assert_eq!(wrapper.0, 42);
+/// assert_eq!(wrapper.1, 7);
+///
+/// stack_init!(wrapper: Wrapper<u64> => {
+///     .0 = 1;
+///     init_u32(.1);
+///     .2 = PhantomPinned;
+/// });
+/// assert_eq!(wrapper.0, 1);
+/// assert_eq!(wrapper.1, 7);
+/// ```
+///
+/// ```rust,compile_fail
+/// use core::{marker::PhantomPinned, mem::MaybeUninit};
+/// use simple_safe_init::*;
+///
+/// pin_data! {
+///     struct Wrapper<T>(#pin T, PhantomPinned);
+/// }
+///
+/// let wrapper = Box::pin(MaybeUninit::uninit());
+/// let wrapper = init! { wrapper => Wrapper<u64> {
+///     .0 = 42;
+/// }};
+/// ```
+///
+/// ```rust,compile_fail
+/// use core::{marker::PhantomPinned, mem::MaybeUninit};
+/// use simple_safe_init::*;
+///
+/// pin_data! {
+///     struct Wrapper<T>(T, PhantomPinned);
+/// }
+///
+/// fn init_u64<G: Guard>(this: PinInitMe<'_, u64, G>) -> InitProof<(), G> {
+///     this.write(42)
+/// }
+///
+/// // `.0` is not structurally pinned
+/// let wrapper = Box::pin(MaybeUninit::uninit());
+/// let wrapper = init! { wrapper => Wrapper<u64> {
+///     init_u64(.0);
+///     .1 = PhantomPinned;
+/// }};
+/// ```
+///
+fn tuple_struct() {}
+
+/// ```rust
+/// use core::mem::MaybeUninit;
+/// use simple_safe_init::*;
+///
+/// #[derive(Debug)]
+/// struct Foo {
+///     a: u32,
+///     b: u64,
+/// }
+///
+/// let mut slots = [MaybeUninit::uninit(), MaybeUninit::uninit()];
+/// let foo: &mut Foo = init! { &mut slots[1] => Foo {
+///     .a = 42;
+///     .b = 30;
+/// }};
+/// foo.a += 1;
+/// assert_eq!(foo.a, 43);
+/// assert_eq!(unsafe { slots[1].assume_init_ref() }.b, 30);
+/// ```
+///
+/// ```rust,compile_fail
+/// use core::{marker::PhantomPinned, mem::MaybeUninit, pin::pin};
+/// use simple_safe_init::*;
+///
+/// struct Foo {
+///     a: u32,
+///     _p: PhantomPinned,
+/// }
+///
+/// // the `Foo` would never be dropped
+/// let foo = init! { pin!(MaybeUninit::uninit()) => Foo {
+///     .a = 42;
+///     ._p = PhantomPinned;
+/// }};
+/// ```
+///
+fn borrowed_place() {}
+
+/// ```rust
+/// use core::{fmt::Debug, mem::MaybeUninit};
+/// use simple_safe_init::*;
+///
+/// pin_data! {
+///     struct Borrowed<'a, T> where T: Debug + 'a {
+///         #pin
+///         inner: &'a T,
+///         len: usize,
+///     }
+/// }
+///
+/// pin_data! {
+///     struct Both<'a: 'b, 'b, T: [?Sized]>(#pin &'a T, &'b T) where T: Debug;
+/// }
+///
+/// let value = 42u64;
+/// let borrowed = Box::pin(MaybeUninit::uninit());
+/// let borrowed = init! { borrowed => Borrowed<u64> {
+///     PinInitMe::write(.inner, &value);
+///     .len = 1;
+/// }};
+/// assert_eq!(*borrowed.inner, 42);
+///
+/// let both = Box::pin(MaybeUninit::uninit());
+/// let both = init! { both => Both<str> {
+///     PinInitMe::write(.0, "Hello");
+///     .1 = "World";
+/// }};
+/// assert_eq!(both.1, "World");
+/// ```
+///
+fn where_clause() {}
+
+/// ```rust
+/// use core::{marker::PhantomPinned, pin::Pin};
+/// use simple_safe_init::{unique::{UniqueArc, UniqueRc}, *};
+/// use std::{rc::Rc, sync::Arc};
+///
+/// pin_data! {
+///     struct Foo {
+///         #pin
+///         count: usize,
+///         #pin
+///         _p: PhantomPinned,
+///     }
+/// }
+///
+/// fn init_count<G: Guard>(count: PinInitMe<'_, usize, G>) -> InitProof<(), G> {
+///     count.write(42)
+/// }
+///
+/// macro_rules! init_foo {
+///     ($ty:ty) => {
+///         init! { @$ty => Foo {
+///             init_count(.count);
+///             ._p = PhantomPinned;
+///         }}.unwrap()
+///     };
+/// }
+///
+/// let foo: Pin<Box<Foo>> = init_foo!(Pin<Box<Foo>>);
+/// assert_eq!(foo.count, 42);
+/// let foo: Pin<Arc<Foo>> = init_foo!(Pin<Arc<Foo>>);
+/// assert_eq!(foo.count, 42);
+/// let foo: Pin<Rc<Foo>> = init_foo!(Pin<Rc<Foo>>);
+/// assert_eq!(foo.count, 42);
+/// let foo: Pin<UniqueArc<Foo>> = init_foo!(Pin<UniqueArc<Foo>>);
+/// assert_eq!(foo.count, 42);
+/// let foo: Pin<UniqueRc<Foo>> = init_foo!(Pin<UniqueRc<Foo>>);
+/// assert_eq!(foo.count, 42);
+/// ```
+///
+fn alloc_pinned() {}
+
+/// ```rust
+/// use core::mem::MaybeUninit;
+/// use simple_safe_init::*;
+///
+/// #[derive(Debug, PartialEq)]
+/// enum Mode {
+///     Fast,
+///     Slow(u32),
+/// }
+///
+/// pin_data! {
+///     struct Foo {
+///         mode: Mode,
+///         limit: u32,
+///     }
+/// }
+///
+/// fn init_fast<G: Guard>(limit: InitMe<'_, u32, G>) -> InitProof<(), G> {
+///     limit.write(1000)
+/// }
+///
+/// fn init_slow<G: Guard>(limit: InitMe<'_, u32, G>, delay: u32) -> InitProof<(), G> {
+///     limit.write(1000 / delay)
+/// }
+///
+/// // branch between two init-functions for the same field
+/// fn init_limit<G: Guard>(limit: InitMe<'_, u32, G>, delay: Option<u32>) -> InitProof<(), G> {
+///     match delay {
+///         None => init_fast(limit),
+///         Some(delay) => init_slow(limit, delay),
+///     }
+/// }
+///
+/// for delay in [None, Some(10)] {
+///     let foo = Box::new(MaybeUninit::uninit());
+///     let foo = init! { foo => Foo {
+///         .mode = match delay {
+///             None => Mode::Fast,
+///             Some(delay) => Mode::Slow(delay),
+///         };
+///         init_limit(.limit, delay);
+///     }};
+///     match delay {
+///         None => assert_eq!((foo.mode, foo.limit), (Mode::Fast, 1000)),
+///         Some(_) => assert_eq!((foo.mode, foo.limit), (Mode::Slow(10), 100)),
+///     }
+/// }
+/// ```
+///
+/// ```rust,compile_fail
+/// use core::mem::MaybeUninit;
+/// use simple_safe_init::*;
+///
+/// struct Foo {
+///     a: u32,
+///     b: u32,
+/// }
+///
+/// let cond = true;
+/// let foo = Box::new(MaybeUninit::uninit());
+/// // `.b` is missing
+/// let foo = init! { foo => Foo {
+///     .a = match cond {
+///         true => 1,
+///         false => 2,
+///     };
+/// }};
+/// ```
+///
+fn match_field() {}
+
+/// ```rust
+/// use core::mem::MaybeUninit;
+/// use simple_safe_init::*;
+///
+/// pin_data! {
+///     struct RingBuf<T: [Copy + Default], const N: usize> {
+///         buf: [T; N],
+///         head: usize,
+///     }
+/// }
+///
+/// pin_data! {
+///     struct Bits<const N: usize>([bool; N]);
+/// }
+///
+/// fn init_buf<G: Guard, T: Copy + Default, const N: usize>(buf: InitMe<'_, [T; N], G>) -> InitProof<(), G> {
+///     buf.write([T::default(); N])
+/// }
+///
+/// fn new_ring<const N: usize>() -> Box<RingBuf<u8, N>> {
+///     let ring = Box::new(MaybeUninit::uninit());
+///     init! { ring => RingBuf<u8, { N }> {
+///         init_buf(.buf);
+///         .head = 0;
+///     }}
+/// }
+///
+/// let ring = new_ring::<16>();
+/// assert_eq!(ring.buf.len(), 16);
+///
+/// let ring = Box::new(MaybeUninit::uninit());
+/// let ring = init! { ring => RingBuf<u32, 4> {
+///     .buf = [1; 4];
+///     .head = 3;
+/// }};
+/// assert_eq!(ring.buf[ring.head], 1);
+///
+/// stack_init!(bits: Bits<2> => {
+///     .0 = [true, false];
+/// });
+/// assert!(bits.0[0]);
+/// ```
+///
+fn const_generics() {}
+
+/// ```rust
+/// use core::mem::{size_of, MaybeUninit};
+/// use simple_safe_init::*;
+///
+/// pin_data! {
+///     #[repr(C)]
+///     struct Header {
+///         tag: u8,
+///         len: u32,
+///         flags: u8,
+///     }
+/// }
+///
+/// // without `#[repr(C)]` the fields would be reordered to fit into 8 bytes
+/// assert_eq!(size_of::<Header>(), 12);
+///
+/// let header = Box::new(MaybeUninit::uninit());
+/// let header = init! { header => Header {
+///     .tag = 1;
+///     .len = 42;
+///     .flags = 0;
+/// }};
+/// assert_eq!(header.len, 42);
+/// ```
+///
+fn repr_c() {}
+
+/// ```rust,compile_fail
+/// #![deny(unused_must_use)]
+/// use simple_safe_init::*;
+///
+/// fn init_count<G: Guard>(count: InitMe<'_, usize, G>) -> InitProof<(), G> {
+///     // the proof is dropped
+///     count.write(42);
+///     todo!()
+/// }
+/// ```
+///
+fn must_use() {}
+
+/// ```rust
+/// use core::marker::PhantomPinned;
+/// use simple_safe_init::*;
+///
+/// pin_data! {
+///     struct Counter {
+///         #pin
+///         count: usize,
+///         #pin
+///         _p: PhantomPinned,
+///     }
+/// }
+///
+/// fn init_counter<G: Guard>(this: PinInitMe<'_, Counter, G>, count: usize) -> InitProof<(), G> {
+///     init! { this => Counter {
+///         .count = count;
+///         ._p = PhantomPinned;
+///     }}
+/// }
+///
+/// static_init! {
+///     [unsafe]
+///     static FIRST: Counter = |this| { init_counter(this, 1) };
+///     pub(crate) static SECOND: Counter = |this| { init_counter(this, 2) };
+/// }
+///
+/// // a ctor that is registered manually
+/// macro_rules! my_ctor {
+///     ($ctor:path) => {
+///         #[used]
+///         #[cfg_attr(target_os = "linux", link_section = ".init_array")]
+///         static MY_CTOR: unsafe extern "C" fn() = $ctor;
+///     };
+/// }
+///
+/// static_init! {
+///     [unsafe { my_ctor }]
+///     static THIRD: Counter = |this| { init_counter(this, 3) };
+/// }
+///
+/// fn main() {
+///     assert_eq!(FIRST.count + SECOND.count, 3);
+///     if cfg!(target_os = "linux") {
+///         assert_eq!(THIRD.count, 3);
+///     }
+/// }
+/// ```
+///
+fn static_init() {}
+
+/// ```rust
+/// use simple_safe_init::unique::{UniqueArc, UniqueRc};
+///
+/// let unique = UniqueArc::new(21);
+/// let unique = UniqueArc::map(unique, |v| v.to_string());
+/// assert_eq!(*unique, "21");
+/// let parsed: Result<UniqueArc<u8>, _> = UniqueArc::try_map(unique, |v| v.parse::<u8>());
+/// assert_eq!(*parsed.unwrap(), 21);
+///
+/// let unique = UniqueRc::new("x");
+/// assert!(UniqueRc::try_map(unique, |v| v.parse::<u8>()).is_err());
+///
+/// assert_eq!(UniqueArc::into_inner(UniqueArc::new(vec![1])), [1]);
+/// assert_eq!(UniqueRc::into_pin_inner(UniqueRc::pin(7)), 7);
+/// ```
+///
+fn unique_map() {}
+
+/// ```rust
+/// use core::mem::MaybeUninit;
+/// use simple_safe_init::*;
+///
+/// pin_data! {
+///     struct Foo {
+///         a: u32,
+///         b: u32,
+///         c: u32,
+///         d: Box<u32>,
+///     }
+/// }
+///
+/// unsafe fn init_d<G: Guard>(d: InitMe<'_, Box<u32>, G>, value: u32) -> Result<InitProof<(), G>, ()> {
+///     Ok(d.write(Box::new(value)))
+/// }
+///
+/// let value = 42u32;
+/// let raw_ptr: *const u32 = &value;
+/// let foo = Box::new(MaybeUninit::uninit());
+/// let foo = (|| {
+///     Ok::<_, ()>(init! { foo => Foo {
+///         unsafe { .a = *(raw_ptr); }
+///         unsafe { .b = *raw_ptr + 1 };
+///         unsafe { .c = raw_ptr.read() - 1; };
+///         unsafe { init_d(.d, *raw_ptr) }?;
+///     }})
+/// })()
+/// .unwrap();
+/// assert_eq!((foo.a, foo.b, foo.c, *foo.d), (42, 43, 41, 42));
+/// ```
+///
+fn unsafe_assignment() {}
+
+/// ```rust,compile_fail
+/// use core::mem::MaybeUninit;
+/// use simple_safe_init::*;
+///
+/// struct Foo {
+///     a: u32,
+///     b: u32,
+/// }
+///
+/// let value = 42u32;
+/// let raw_ptr: *const u32 = &value;
+/// let foo = Box::new(MaybeUninit::uninit());
+/// let foo = init! { foo => Foo {
+///     unsafe {
+///         .a = *raw_ptr;
+///         .b = *raw_ptr + 1;
+///     }
+/// }};
+/// ```
+///
+fn unsafe_assignment_single() {}
+
+/// ```rust
+/// use simple_safe_init::{unique::*, *};
+/// use std::rc::Rc;
+///
+/// fn init_strings<G: Guard>(slice: InitMe<'_, [String], G>) -> InitProof<(), G> {
+///     slice.write_each(|i| i.to_string())
+/// }
+///
+/// let unique: UniqueRc<[String]> = init_with_len!(@init_strings(UniqueRc<[String]>, 2)).unwrap();
+/// assert_eq!(*unique, ["0".to_owned(), "1".to_owned()]);
+/// let shared: Rc<[String]> = init_with_len!(@init_strings(Rc<[String]>, 0)).unwrap();
+/// assert!(shared.is_empty());
+///
+/// let boxed = Box::<[u8]>::new_uninit_slice(3);
+/// let boxed = init!(InitMe::write_each(boxed, |i| i as u8));
+/// assert_eq!(*boxed, [0, 1, 2]);
+/// ```
+///
+fn slice_places() {}
+
+/// ```rust
+/// use core::{
+///     future::Future,
+///     mem::MaybeUninit,
+///     task::{Context, Poll, Waker},
+/// };
+/// use simple_safe_init::*;
+///
+/// pin_data! {
+///     struct Foo {
+///         a: String,
+///         b: usize,
+///         c: u64,
+///         d: Vec<u8>,
+///     }
+/// }
+///
+/// async fn double(x: usize) -> usize {
+///     x * 2
+/// }
+///
+/// async fn init_foo(name: String) -> Box<Foo> {
+///     let foo = Box::new(MaybeUninit::uninit());
+///     init! { foo => Foo {
+///         .a = (move || name)();
+///         .b = (|| async { double(21).await })().await;
+///         .c = (|| -> u64 { 7 })();
+///         .d = (move |len: usize| -> Vec<u8> { vec![0; len] })(3);
+///     }}
+/// }
+///
+/// let foo = Box::new(MaybeUninit::uninit());
+/// let name = "closure".to_owned();
+/// let foo = init! { foo => Foo {
+///     .a = (move || name)();
+///     .b = (|| { let x = 21; x * 2 })();
+///     .c = (|| -> u64 { 7 })();
+///     .d = (move |len: usize| -> Vec<u8> { vec![0; len] })(3);
+/// }};
+/// assert_eq!((&*foo.a, foo.b, foo.c, &*foo.d), ("closure", 42, 7, &[0u8; 3][..]));
+///
+/// let fut = core::pin::pin!(init_foo("async".to_owned()));
+/// let mut cx = Context::from_waker(Waker::noop());
+/// let Poll::Ready(foo) = fut.poll(&mut cx) else { panic!() };
+/// assert_eq!((&*foo.a, foo.b, foo.c, &*foo.d), ("async", 42, 7, &[0u8; 3][..]));
+/// ```
+///
+fn closure_initializers() {}
+
+/// ```rust
+/// use core::fmt::Debug;
+/// use simple_safe_init::{place::___PinData, *};
+///
+/// pin_data! {
+///     #[derive(Debug)]
+///     pub struct Foo<T> {
+///         #pin
+///         pub value: T,
+///     }
+/// }
+///
+/// pin_data! {
+///     #[derive(Debug)]
+///     pub struct Bar(#pin pub u8, u32);
+/// }
+///
+/// fn assert_debug<T: Debug>() {}
+///
+/// assert_debug::<Foo<u8>>();
+/// assert_debug::<<Foo<u8> as ___PinData>::___PinData>();
+/// assert_debug::<<Bar as ___PinData>::___PinData>();
+/// ```
+///
+fn pin_data_debug() {}
+
+/// ```rust
+/// use core::mem::MaybeUninit;
+/// use simple_safe_init::*;
+///
+/// pin_data! {
+///     struct Foo {
+///         a: u32,
+///         b: u64,
+///         c: String,
+///         d: (u32, u64),
+///     }
+/// }
+///
+/// fn init_a<G: Guard>(a: InitMe<'_, u32, G>, val: u32) -> InitProof<u32, G> {
+///     a.write(val).ret(val * 2)
+/// }
+///
+/// fn init_b<G: Guard>(b: InitMe<'_, u64, G>, val: u32) -> Result<InitProof<(u64, bool), G>, ()> {
+///     let val = u64::from(val) + 1;
+///     Ok(b.write(val).ret((val, val % 2 == 0)))
+/// }
+///
+/// macro_rules! init_c {
+///     ($c:ident, $even:expr) => {
+///         $c.write(if $even { "even" } else { "odd" }.to_owned()).ret($even)
+///     };
+/// }
+///
+/// let foo = Box::new(MaybeUninit::uninit());
+/// let foo = (|| {
+///     Ok::<_, ()>(init! { foo => Foo {
+///         ~let doubled = init_a(.a, 20);
+///         ~let (b, even) = init_b(.b, doubled)?;
+///         ~let _ = init_c!(.c, even);
+///         .d = (doubled, b);
+///     }})
+/// })()
+/// .unwrap();
+/// assert_eq!((foo.a, foo.b, &*foo.c, foo.d), (20, 41, "odd", (40, 41)));
+/// ```
+///
+fn let_chaining() {}
+
+/// ```rust
+/// use core::{marker::PhantomPinned, mem::MaybeUninit, pin::Pin};
+/// use simple_safe_init::*;
+///
+/// pin_data! {
+///     #[derive(Debug)]
+///     struct Wrapper<'a, T> {
+///         inner: T,
+///         name: &'a str,
+///         #pin
+///         _p: PhantomPinned,
+///     }
+/// }
+///
+/// fn init_name<'a, G: Guard>(name: InitMe<'_, &'a str, G>, value: &'a str) -> InitProof<(), G> {
+///     name.write(value)
+/// }
+///
+/// let name = "wrapper".to_owned();
+/// let val = 42;
+/// let wrapper = Box::pin(MaybeUninit::uninit());
+/// let wrapper = init! { wrapper => Wrapper<'_, u32> {
+///     .inner = val;
+///     init_name(.name, &name);
+///     ._p = PhantomPinned;
+/// }};
+/// assert_eq!((wrapper.inner, wrapper.name), (42, "wrapper"));
+///
+/// let wrapper = init! { @Pin<Box<Wrapper<'static, Vec<u8>>>> => Wrapper<'static, Vec<u8>> {
+///     .inner = vec![1];
+///     .name = "static";
+///     ._p = PhantomPinned;
+/// }}
+/// .unwrap();
+/// assert_eq!(wrapper.name, "static");
+///
+/// pin_data! {
+///     struct Pair<'a, 'b> {
+///         first: &'a str,
+///         second: &'b str,
+///     }
+/// }
+///
+/// let pair = Box::new(MaybeUninit::uninit());
+/// let pair = init! { pair => Pair<'_, '_> {
+///     .first = &name;
+///     .second = "second";
+/// }};
+/// assert_eq!((pair.first, pair.second), ("wrapper", "second"));
+/// ```
+///
+fn lifetime_arguments() {}
+
+/// ```rust
+/// use core::{marker::PhantomPinned, mem::MaybeUninit};
+/// use simple_safe_init::*;
+///
+/// pin_data! {
+///     struct Foo {
+///         a: u32,
+///         #pin
+///         b: u64,
+///         c: usize,
+///         #pin
+///         _pin: PhantomPinned,
+///     }
+/// }
+///
+/// fn init_ab<G1: Guard, G2: Guard>(
+///     a: InitMe<'_, u32, G1>,
+///     b: PinInitMe<'_, u64, G2>,
+///     value: u32,
+/// ) -> InitProof<usize, (G1, G2)> {
+///     a.write(value).chain(b.write(value as u64 * 2)).ret(3)
+/// }
+///
+/// fn try_init_ab<G1: Guard, G2: Guard>(
+///     a: InitMe<'_, u32, G1>,
+///     b: PinInitMe<'_, u64, G2>,
+/// ) -> Result<InitProof<(u32, u64), (G1, G2)>, ()> {
+///     Ok(a.write(1).ret(1).zip(b.write(2).ret(2)))
+/// }
+///
+/// let foo = Box::pin(MaybeUninit::uninit());
+/// let foo = init! { foo => Foo {
+///     ~let c = init_ab(.a, .b, 21);
+///     .c = c;
+///     ._pin = PhantomPinned;
+/// }};
+/// assert_eq!((foo.a, foo.b, foo.c), (21, 42, 3));
+///
+/// let foo = Box::pin(MaybeUninit::uninit());
+/// let foo = (|| {
+///     Ok::<_, ()>(init! { foo => Foo {
+///         ~let (a, b) = try_init_ab(.a, .b)?;
+///         .c = a as usize + b as usize;
+///         ._pin = PhantomPinned;
+///     }})
+/// })()
+/// .unwrap();
+/// assert_eq!((foo.a, foo.b, foo.c), (1, 2, 3));
+/// ```
+///
+/// ```rust,compile_fail
+/// use core::mem::MaybeUninit;
+/// use simple_safe_init::*;
+///
+/// pin_data! {
+///     struct Foo {
+///         a: u32,
+///         b: u32,
+///     }
+/// }
+///
+/// fn init_two<G1: Guard, G2: Guard>(
+///     x: InitMe<'_, u32, G1>,
+///     y: InitMe<'_, u32, G2>,
+/// ) -> InitProof<(), (G1, G2)> {
+///     x.write(1).chain(y.write(2))
+/// }
+///
+/// let foo = Box::new(MaybeUninit::uninit());
+/// let foo = init! { foo => Foo {
+///     init_two(.a, .a);
+///     .b = 3;
+/// }};
+/// ```
+///
+fn two_field_init() {}
+
+/// ```rust
+/// use core::mem::MaybeUninit;
+/// use simple_safe_init::*;
+///
+/// struct Inner {
+///     value: u32,
+/// }
+///
+/// impl Inner {
+///     fn init<G: Guard>(this: InitMe<'_, Self, G>, value: u32) -> InitProof<(), G> {
+///         init! { this => Self {
+///             .value = value;
+///         }}
+///     }
+/// }
+///
+/// pin_data! {
+///     #[repr(transparent)]
+///     struct Wrapper(Inner);
+/// }
+///
+/// pin_data! {
+///     #[repr(transparent)]
+///     struct Named {
+///         inner: Inner,
+///     }
+/// }
+///
+/// let wrapper = init! { Box::new(MaybeUninit::uninit()) => Wrapper {
+///     Inner::init(.0, 1);
+/// }};
+/// let named = init! { Box::new(MaybeUninit::uninit()) => Named {
+///     .inner = Inner { value: 2 };
+/// }};
+/// assert_eq!((wrapper.0.value, named.inner.value), (1, 2));
+/// ```
+///
+fn transparent_newtype() {}
+
+/// ```rust
+/// use core::{fmt::Display, mem::MaybeUninit};
+/// use simple_safe_init::*;
+///
+/// pin_data! {
+///     struct Foo {
+///         a: u32,
+///         b: u32,
+///     }
+/// }
+///
+/// fn init_display<G: Guard>(this: InitMe<'_, u32, G>, value: u32) -> InitProof<impl Display, G> {
+///     this.write(value).ret(format!("value: {value}"))
+/// }
+///
+/// let foo = init! { Box::new(MaybeUninit::uninit()) => Foo {
+///     // the returned value is ignored without a binding
+///     init_display(.a, 1);
+///     ~let msg = init_display(.b, 2);
+///     assert_eq!(msg.to_string(), "value: 2");
+/// }};
+/// assert_eq!((foo.a, foo.b), (1, 2));
+/// ```
+///
+fn impl_trait_return() {}
+
+/// ```rust
+/// use core::{marker::PhantomPinned, mem::MaybeUninit, pin::Pin};
+/// use serde::de::{value::Error, IntoDeserializer};
+/// use simple_safe_init::*;
+///
+/// pin_data! {
+///     struct Entry {
+///         id: u64,
+///         #pin
+///         key: String,
+///         #pin
+///         _pin: PhantomPinned,
+///     }
+/// }
+///
+/// fn entry(id: i64, key: &str) -> Result<Pin<Box<Entry>>, Error> {
+///     let entry = Box::pin(MaybeUninit::uninit());
+///     Ok(init! { entry => Entry {
+///         de::deserialize(.id, id.into_deserializer())?;
+///         de::deserialize_pinned(.key, key.into_deserializer())?;
+///         ._pin = PhantomPinned;
+///     }})
+/// }
+///
+/// let foo = entry(42, "answer").unwrap();
+/// assert_eq!((foo.id, &*foo.key), (42, "answer"));
+/// assert!(entry(-1, "answer").is_err());
+/// ```
+///
+#[cfg(feature = "serde")]
+fn serde_places() {}
+
+/// ```rust,compile_fail
+/// use simple_safe_init::*;
+///
+/// pin_data! {
+///     struct Big {
+///         data: [u8; MAX_STACK_INIT_SIZE + 1],
+///     }
+/// }
+///
+/// stack_init!(big: Big => {
+///     .data = [0; MAX_STACK_INIT_SIZE + 1];
+/// });
+/// ```
+///
+#[cfg(feature = "stack_limit")]
+fn stack_limit() {}
+
+/// ```rust
+/// use core::{marker::PhantomPinned, mem::MaybeUninit};
+/// use simple_safe_init::*;
+///
+/// mod other {
+///     #[derive(Debug, PartialEq)]
+///     pub struct PhantomData(pub u32);
+/// }
+///
+/// #[derive(PinInit)]
+/// struct Foo {
+///     a: u32,
+///     marker: core::marker::PhantomData<u8>,
+///     pin: std::marker::PhantomPinned,
+///     _pin: PhantomPinned,
+///     // not `core::marker::PhantomData`, so it still needs a parameter
+///     other: other::PhantomData,
+/// }
+///
+/// let foo = init!(Foo::pin_init(Box::pin(MaybeUninit::uninit()), 1, other::PhantomData(2)));
+/// assert_eq!((foo.a, &foo.other), (1, &other::PhantomData(2)));
+/// ```
+///
+#[cfg(feature = "derive")]
+fn derive_phantom_paths() {}
@@ -3,19 +3,21 @@
 //! Inspired by [pin-init](https://docs.rs/pin-init/0.2.0/pin_init/index.html) and [servo_arc](https://docs.rs/servo_arc/latest/servo_arc/struct.UniqueArc.html).
 
 use super::{
-    place::{AllocablePlace, PartialInitPlace},
+    place::{AllocError, AllocablePlace, LenAllocablePlace, OwnedPlace, PartialInitPlace},
     Guard, InitMe,
 };
-use alloc::{alloc::AllocError, rc::Rc, sync::Arc};
+use alloc::{rc::Rc, sync::Arc};
 use core::{
+    convert::Infallible,
     fmt,
     mem::MaybeUninit,
     ops::{Deref, DerefMut},
     pin::Pin,
+    ptr,
 };
 
 macro_rules! make_unique {
-    ($(#[$attr:meta])* $name:ident, $orig:ident ) => {
+    ($(#[$attr:meta])* $name:ident, $orig:ident, $try_from:ident, $mod:ident) => {
         $(#[$attr])*
         #[derive(Debug, Ord, Hash, PartialOrd, Eq, PartialEq)]
         pub struct $name<T: ?Sized> {
@@ -42,7 +44,10 @@ macro_rules! make_unique {
 
             #[doc = concat!("Constructs a new [`", stringify!($name), "<T>`], returning an error if allocation fails.")]
             pub fn try_new(data: T) -> Result<Self, AllocError> {
-                $orig::try_new(data).map(|inner| Self { inner })
+                #[cfg(nightly)]
+                return $orig::try_new(data).map(|inner| Self { inner });
+                #[cfg(not(nightly))]
+                return Ok(Self::new(data));
             }
 
             #[doc = concat!("Constructs a new [`", stringify!($name), "<T>`], returning an error if allocation fails.")]
@@ -50,9 +55,65 @@ macro_rules! make_unique {
                 // SAFETY: we will be pinned indefinetly.
                 Self::try_new(data).map(|s| unsafe { Pin::new_unchecked(s) })
             }
+
+            /// Transforms the contained value.
+            ///
+            #[doc = concat!("The value is moved out of its allocation, so the returned [`", stringify!($name), "<U>`] is a new allocation.")]
+            pub fn map<U, F: FnOnce(T) -> U>(this: Self, f: F) -> $name<U> {
+                $name::new(f(Self::into_inner(this)))
+            }
+
+            /// Transforms the contained value with a fallible function.
+            ///
+            /// The original value is consumed by `f`, so only the error is returned on failure.
+            pub fn try_map<U, E, F: FnOnce(T) -> Result<U, E>>(this: Self, f: F) -> Result<$name<U>, E> {
+                f(Self::into_inner(this)).map($name::new)
+            }
+
+            /// Returns the contained value.
+            pub fn into_inner(this: Self) -> T {
+                let Ok(value) = $orig::try_unwrap(this.inner) else {
+                    unreachable!(concat!("a ", stringify!($name), " is the only pointer to its value"))
+                };
+                value
+            }
+
+            /// Returns the contained value of a pinned pointer, `T` needs to be [`Unpin`].
+            pub fn into_pin_inner(this: Pin<Self>) -> T
+            where
+                T: Unpin,
+            {
+                Self::into_inner(Pin::into_inner(this))
+            }
         }
 
         impl<T: ?Sized> $name<T> {
+            #[doc = concat!("Converts a [`", stringify!($orig), "<T>`] into a [`", stringify!($name), "<T>`], if there are no other")]
+            #[doc = concat!("[`", stringify!($orig), "`] or [`Weak`] pointers to the same allocation.")]
+            /// Otherwise the pointer is returned unchanged.
+            ///
+            /// ```rust
+            #[doc = concat!("use simple_safe_init::unique::", stringify!($name), ";")]
+            #[doc = concat!("use std::", stringify!($mod), "::", stringify!($orig), ";")]
+            ///
+            #[doc = concat!("let shared = ", stringify!($orig), "::new(42);")]
+            #[doc = concat!("let other = ", stringify!($orig), "::clone(&shared);")]
+            #[doc = concat!("let shared = ", stringify!($name), "::", stringify!($try_from), "(shared).unwrap_err();")]
+            /// drop(other);
+            #[doc = concat!("let mut unique = ", stringify!($name), "::", stringify!($try_from), "(shared).unwrap();")]
+            /// *unique += 1;
+            #[doc = concat!("assert_eq!(*", stringify!($name), "::share(unique), 43);")]
+            /// ```
+            ///
+            #[doc = concat!("[`Weak`]: alloc::", stringify!($mod), "::Weak")]
+            pub fn $try_from(mut this: $orig<T>) -> Result<Self, $orig<T>> {
+                if $orig::get_mut(&mut this).is_some() {
+                    Ok(Self { inner: this })
+                } else {
+                    Err(this)
+                }
+            }
+
             #[doc = concat!("Convert to a sharable [`", stringify!($orig), "<T>`].")]
             pub fn share(this: Self) -> $orig<T> {
                 this.inner
@@ -72,6 +133,14 @@ macro_rules! make_unique {
             }
         }
 
+        impl<T: ?Sized> From<$name<T>> for Pin<$name<T>> {
+            fn from(unique: $name<T>) -> Self {
+                // SAFETY: the pointee is only reachable through `unique`, which is moved into the
+                // `Pin`, so it cannot be moved until it is dropped.
+                unsafe { Pin::new_unchecked(unique) }
+            }
+        }
+
         impl<T: ?Sized> Deref for $name<T> {
             type Target = T;
 
@@ -83,7 +152,12 @@ macro_rules! make_unique {
         impl<T: ?Sized> DerefMut for $name<T> {
             fn deref_mut(&mut self) -> &mut Self::Target {
                 // SAFETY: No other arcs exist that point to the same place.
-                unsafe { $orig::get_mut_unchecked(&mut self.inner) }
+                #[cfg(nightly)]
+                return unsafe { $orig::get_mut_unchecked(&mut self.inner) };
+                // SAFETY: No other arcs or weak pointers exist that point to the same place, so
+                // `get_mut` always succeeds.
+                #[cfg(not(nightly))]
+                return unsafe { $orig::get_mut(&mut self.inner).unwrap_unchecked() };
             }
         }
 
@@ -107,6 +181,30 @@ macro_rules! make_unique {
             }
         }
 
+        unsafe impl<T> OwnedPlace for $name<MaybeUninit<T>> {}
+
+        unsafe impl<T> PartialInitPlace for $name<[MaybeUninit<T>]> {
+            type Init = $name<[T]>;
+            type Raw = [T];
+            type InitMe<'a, G: Guard> = InitMe<'a, [T], G> where Self: 'a;
+
+            unsafe fn ___assume_init(this: Self) -> Self::Init {
+                $name {
+                    // SAFETY: all elements have been initialized
+                    inner: unsafe { $orig::<[MaybeUninit<T>]>::assume_init(this.inner) },
+                }
+            }
+
+            unsafe fn ___as_mut_ptr(
+                this: &mut Self,
+                _proof: &impl FnOnce(&Self::Raw),
+            ) -> *mut Self::Raw {
+                ptr::slice_from_raw_parts_mut(this.as_mut_ptr().cast::<T>(), this.len())
+            }
+        }
+
+        unsafe impl<T> OwnedPlace for $name<[MaybeUninit<T>]> {}
+
         impl<T> AllocablePlace for $name<T> {
             type Error = AllocError;
             type Alloced = $name<MaybeUninit<T>>;
@@ -134,15 +232,71 @@ macro_rules! make_unique {
                 $name::share(alloced)
             }
         }
+
+        impl<T> LenAllocablePlace for $name<[T]> {
+            type Error = Infallible;
+            type Alloced = $name<[MaybeUninit<T>]>;
+            type Final = $name<[T]>;
+
+            fn allocate(len: usize) -> Result<Self::Alloced, Self::Error> {
+                Ok($name { inner: $orig::new_uninit_slice(len) })
+            }
+
+            fn after_init(alloced: <Self::Alloced as PartialInitPlace>::Init) -> Self::Final {
+                alloced
+            }
+        }
+
+        impl<T> LenAllocablePlace for $orig<[T]> {
+            type Error = Infallible;
+            type Alloced = $name<[MaybeUninit<T>]>;
+            type Final = $orig<[T]>;
+
+            fn allocate(len: usize) -> Result<Self::Alloced, Self::Error> {
+                Ok($name { inner: $orig::new_uninit_slice(len) })
+            }
+
+            fn after_init(alloced: <Self::Alloced as PartialInitPlace>::Init) -> Self::Final {
+                $name::share(alloced)
+            }
+        }
+
+        impl<T> AllocablePlace for Pin<$name<T>> {
+            type Error = AllocError;
+            type Alloced = Pin<$name<MaybeUninit<T>>>;
+            type Final = Pin<$name<T>>;
+
+            fn allocate() -> Result<Self::Alloced, Self::Error> {
+                $name::try_new(MaybeUninit::uninit()).map(Pin::from)
+            }
+
+            fn after_init(alloced: <Self::Alloced as PartialInitPlace>::Init) -> Self::Final {
+                alloced
+            }
+        }
+
+        impl<T> AllocablePlace for Pin<$orig<T>> {
+            type Error = AllocError;
+            type Alloced = Pin<$name<MaybeUninit<T>>>;
+            type Final = Pin<$orig<T>>;
+
+            fn allocate() -> Result<Self::Alloced, Self::Error> {
+                $name::try_new(MaybeUninit::uninit()).map(Pin::from)
+            }
+
+            fn after_init(alloced: <Self::Alloced as PartialInitPlace>::Init) -> Self::Final {
+                $name::pin_share(alloced)
+            }
+        }
     };
 }
 
 make_unique! {
     /// [`Arc<T>`] but with reference count equal to 1.
-    UniqueArc, Arc
+    UniqueArc, Arc, try_from_arc, sync
 }
 
 make_unique! {
     /// [`Rc<T>`] but with reference count equal to 1.
-    UniqueRc, Rc
+    UniqueRc, Rc, try_from_rc, rc
 }